@@ -0,0 +1,244 @@
+//! Active abilities that spend [`PlayerPower`], trading progress towards the
+//! next multiplier for a way out of trouble.
+//!
+//! The selected ability is cycled with tab or the number keys and used with
+//! shift, the right mouse button or a second finger on touch screens.
+
+use bevy::{color::palettes::css::GRAY, prelude::*};
+
+use crate::{
+    locale::Locale,
+    mode::GameMode,
+    player::{Player, PlayerPower, PlayerShield},
+    screens::Screen,
+    supernova::Nova,
+    theme::layout,
+    utils::{DestroyAt, MoveInDirection, ScaleTextOverTime},
+};
+
+/// How much faster the player orbits while boosting
+pub const BOOST_SPEED_MULTIPLIER: f32 = 1.6;
+const BOOST_DURATION: f32 = 3.0;
+/// The shield restored by a shield burst
+const SHIELD_BURST_AMOUNT: f32 = 35.0;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<Ability>();
+    app.register_type::<SelectedAbility>();
+    app.register_type::<Boost>();
+    app.register_type::<Phase>();
+    app.register_type::<AbilityMarker>();
+
+    app.init_resource::<SelectedAbility>();
+
+    app.add_systems(OnEnter(Screen::Gameplay), spawn_ability_text);
+    app.add_systems(
+        Update,
+        (
+            // the keys are the menu's while an attract run plays behind it
+            (select_ability, use_ability.run_if(in_state(Nova::Idle)))
+                .run_if(not(resource_equals(GameMode::Attract))),
+            tick_boost,
+            update_ability_text,
+        )
+            .chain()
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+#[derive(Reflect, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Ability {
+    /// Orbit faster for a few seconds
+    #[default]
+    Boost,
+    /// Pass through the next obstacle unharmed
+    Phase,
+    /// Convert power into shield
+    ShieldBurst,
+}
+
+impl Ability {
+    const ALL: [Ability; 3] = [Ability::Boost, Ability::Phase, Ability::ShieldBurst];
+
+    /// The locale key for the ability's name
+    pub fn name_key(&self) -> &'static str {
+        match self {
+            Ability::Boost => "ability.boost",
+            Ability::Phase => "ability.phase",
+            Ability::ShieldBurst => "ability.shield_burst",
+        }
+    }
+
+    /// The power spent using the ability
+    pub fn cost(&self) -> f32 {
+        match self {
+            Ability::Boost => 30.0,
+            Ability::Phase => 40.0,
+            Ability::ShieldBurst => 50.0,
+        }
+    }
+
+    fn next(&self) -> Self {
+        match self {
+            Ability::Boost => Ability::Phase,
+            Ability::Phase => Ability::ShieldBurst,
+            Ability::ShieldBurst => Ability::Boost,
+        }
+    }
+}
+
+#[derive(Resource, Reflect, Debug, Default)]
+#[reflect(Resource)]
+pub struct SelectedAbility(pub Ability);
+
+/// Added to the player while boosting
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+pub struct Boost(pub Timer);
+
+/// Added to the player to let them pass through a single obstacle
+#[derive(Component, Reflect, Debug, Default)]
+#[reflect(Component)]
+pub struct Phase {
+    /// The obstacle currently being passed through, the phase ends once the
+    /// player is clear of it
+    pub through: Option<Entity>,
+}
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct AbilityMarker;
+
+fn spawn_ability_text(mut commands: Commands) {
+    commands.spawn((
+        Text::new(""),
+        layout::font_size(20.0, 16.0),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(120.0),
+            right: Val::Px(10.0),
+            ..default()
+        },
+        AbilityMarker,
+        StateScoped(Screen::Gameplay),
+    ));
+}
+
+fn select_ability(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut selected: ResMut<SelectedAbility>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Tab) {
+        selected.0 = selected.0.next();
+    }
+
+    for (key, ability) in [KeyCode::Digit1, KeyCode::Digit2, KeyCode::Digit3]
+        .into_iter()
+        .zip(Ability::ALL)
+    {
+        if keyboard_input.just_pressed(key) {
+            selected.0 = ability;
+        }
+    }
+}
+
+fn use_ability(
+    mut commands: Commands,
+    time: Res<Time>,
+    locale: Res<Locale>,
+    selected: Res<SelectedAbility>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
+    player: Single<
+        (
+            Entity,
+            &Transform,
+            &mut PlayerPower,
+            &mut PlayerShield,
+            Has<Boost>,
+            Has<Phase>,
+        ),
+        With<Player>,
+    >,
+) {
+    let pressed = keyboard_input.any_just_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight])
+        || mouse.just_pressed(MouseButton::Right)
+        || (touches.any_just_pressed() && touches.iter().count() > 1);
+
+    if !pressed {
+        return;
+    }
+
+    let (entity, tx, mut power, mut shield, boosting, phasing) = player.into_inner();
+    let ability = selected.0;
+
+    if power.0 < ability.cost() {
+        return;
+    }
+
+    match ability {
+        Ability::Boost if boosting => return,
+        Ability::Boost => {
+            commands
+                .entity(entity)
+                .insert(Boost(Timer::from_seconds(BOOST_DURATION, TimerMode::Once)));
+        }
+        Ability::Phase if phasing => return,
+        Ability::Phase => {
+            commands.entity(entity).insert(Phase::default());
+        }
+        Ability::ShieldBurst if shield.current >= shield.max => return,
+        Ability::ShieldBurst => {
+            shield.current = (shield.current + SHIELD_BURST_AMOUNT).min(shield.max);
+        }
+    }
+
+    power.0 -= ability.cost();
+
+    commands.spawn((
+        StateScoped(Screen::Gameplay),
+        Text2d::new(locale.get(ability.name_key())),
+        TextFont::from_font_size(20.0),
+        Transform::from_translation(tx.translation),
+        ScaleTextOverTime {
+            rate: 12.0,
+            max: 32.0,
+        },
+        MoveInDirection(tx.translation.truncate().normalize() * 100.0),
+        DestroyAt {
+            time: time.elapsed_secs() + 2.0,
+        },
+    ));
+}
+
+fn tick_boost(mut commands: Commands, time: Res<Time>, mut boosts: Query<(Entity, &mut Boost)>) {
+    for (entity, mut boost) in &mut boosts {
+        if boost.0.tick(time.delta()).finished() {
+            commands.entity(entity).remove::<Boost>();
+        }
+    }
+}
+
+fn update_ability_text(
+    selected: Res<SelectedAbility>,
+    locale: Res<Locale>,
+    power: Single<&PlayerPower>,
+    text: Single<(&mut Text, &mut TextColor), With<AbilityMarker>>,
+) {
+    let (mut text, mut color) = text.into_inner();
+    let ability = selected.0;
+
+    text.0 = locale.format(
+        "hud.ability",
+        &[
+            ("ability", &locale.get(ability.name_key())),
+            ("cost", &format!("{:.0}", ability.cost())),
+        ],
+    );
+    color.0 = if power.0 >= ability.cost() {
+        Color::WHITE
+    } else {
+        GRAY.into()
+    };
+}
@@ -0,0 +1,71 @@
+//! The attract mode: behind the main menu, a real run plays with the autopilot
+//! at the controls and the HUD hidden. It starts as soon as the title screen
+//! opens, and starts again whenever the autopilot dies, until a run is started
+//! from the menu.
+
+use bevy::prelude::*;
+
+use crate::{
+    asset_tracking::ResourceHandles, autopilot::Autopilot, daily::DailyChallenge, mode::GameMode,
+    screens::Screen,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        start_attract_mode.run_if(in_state(Screen::Title).and(all_assets_loaded)),
+    );
+
+    app.add_systems(
+        OnEnter(Screen::Gameplay),
+        take_controls.run_if(resource_equals(GameMode::Attract)),
+    );
+    app.add_systems(
+        OnExit(Screen::Gameplay),
+        hand_back_controls.run_if(resource_equals(GameMode::Attract)),
+    );
+    app.add_systems(
+        PostUpdate,
+        hide_hud.run_if(in_state(Screen::Gameplay).and(resource_equals(GameMode::Attract))),
+    );
+    app.add_systems(
+        Last,
+        skip_game_over.run_if(in_state(Screen::Gameplay).and(resource_equals(GameMode::Attract))),
+    );
+}
+
+fn all_assets_loaded(resource_handles: Res<ResourceHandles>) -> bool {
+    resource_handles.is_all_done()
+}
+
+fn start_attract_mode(mut commands: Commands, mut next_screen: ResMut<NextState<Screen>>) {
+    commands.insert_resource(GameMode::Attract);
+    commands.remove_resource::<DailyChallenge>();
+    next_screen.set(Screen::Gameplay);
+}
+
+fn take_controls(mut commands: Commands) {
+    commands.insert_resource(Autopilot);
+}
+
+fn hand_back_controls(mut commands: Commands) {
+    commands.remove_resource::<Autopilot>();
+}
+
+/// Hides the HUD, which is every UI root scoped to the run rather than to the
+/// menu in front of it
+fn hide_hud(
+    mut roots: Query<&mut Visibility, (With<Node>, With<StateScoped<Screen>>, Without<ChildOf>)>,
+) {
+    for mut visibility in &mut roots {
+        visibility.set_if_neq(Visibility::Hidden);
+    }
+}
+
+/// Goes back through the title screen when the autopilot dies, starting the
+/// next attract run rather than showing the game over screen
+fn skip_game_over(mut next_screen: ResMut<NextState<Screen>>) {
+    if matches!(*next_screen, NextState::Pending(Screen::GameOver)) {
+        next_screen.set(Screen::Title);
+    }
+}
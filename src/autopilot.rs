@@ -0,0 +1,234 @@
+//! A pilot that flies the ship by writing [`ThrustInput`], seeing only what a
+//! player would: the ship, its shield and the threats on its path.
+//!
+//! Inserting [`Autopilot`] hands it the whole run, for the attract mode and
+//! balance simulations. Otherwise the dodge assist setting lets it take over
+//! only when the player is about to hit something.
+
+use avian2d::prelude::{Collider, LinearVelocity};
+use bevy::prelude::*;
+
+use crate::{
+    AppSystems,
+    consts::MAX_PLAYER_RADIUS,
+    input::{PlayerInputAngle, ThrustInput, record_thrust},
+    materials::BarDataSource,
+    obstacle::{BlackHole, Obstacle},
+    player::{ItemPosition, Player, PlayerShield, ShipStats},
+    save::SaveData,
+    screens::Screen,
+    sun::Sun,
+    supernova::Nova,
+    threats::{self, Approach},
+};
+
+/// How far ahead the autopilot plans, in seconds
+const LOOKAHEAD: f32 = 2.5;
+/// How far ahead the dodge assist checks the player's course
+const ASSIST_LOOKAHEAD: f32 = 1.2;
+/// How long the ship takes to respond to a change of thrust
+const RESPONSE_TIME: f32 = 0.1;
+
+/// The closest the ship passes to the centre of each kind of threat
+const OBSTACLE_CLEARANCE: f32 = 30.0;
+const BLACK_HOLE_CLEARANCE: f32 = 50.0;
+/// The spacing of the radii the autopilot considers heading for
+const RADIUS_STEP: f32 = 10.0;
+/// How much worse a collision is than being a pixel away from the goal
+const HIT_COST: f32 = 10_000.0;
+/// The distance from the target radius at which the ship starts to slow down
+const SLOWDOWN_DISTANCE: f32 = 30.0;
+
+/// Skimming drains the shield, so the autopilot backs off to recharge below
+/// the first fraction and goes back to skimming above the second. Both are
+/// well clear of the low shield alarm.
+const RECHARGE_BELOW: f32 = 0.4;
+const SKIM_ABOVE: f32 = 0.9;
+/// Close enough to the sun for full power while still recharging the shield
+const RECHARGE_RADIUS: f32 = 70.0;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<Autopilot>();
+    app.register_type::<PilotState>();
+
+    app.init_resource::<PilotState>();
+
+    app.add_systems(OnEnter(Screen::Gameplay), reset_pilot_state);
+    app.add_systems(
+        Update,
+        fly_ship
+            .in_set(AppSystems::RecordInput)
+            .after(record_thrust)
+            .run_if(
+                in_state(Screen::Gameplay)
+                    .and(resource_exists::<Autopilot>.or(dodge_assist_enabled)),
+            ),
+    );
+}
+
+/// Hands the ship to the autopilot for as long as this resource exists
+#[derive(Resource, Reflect, Debug, Default)]
+#[reflect(Resource)]
+pub struct Autopilot;
+
+#[derive(Resource, Reflect, Debug, Default)]
+#[reflect(Resource)]
+struct PilotState {
+    /// Whether the ship is keeping away from the sun until the shield recovers
+    recharging: bool,
+    /// The ship's angle last frame, to measure how quickly it orbits
+    last_theta: Option<f32>,
+}
+
+fn dodge_assist_enabled(save: Res<SaveData>) -> bool {
+    save.dodge_assist
+}
+
+fn reset_pilot_state(mut state: ResMut<PilotState>) {
+    *state = PilotState::default();
+}
+
+/// A threat the ship will pass within the lookahead
+struct Threat {
+    approach: Approach,
+    clearance: f32,
+}
+
+fn fly_ship(
+    time: Res<Time>,
+    autopilot: Option<Res<Autopilot>>,
+    nova: Res<State<Nova>>,
+    input: Res<PlayerInputAngle>,
+    mut state: ResMut<PilotState>,
+    mut thrust: ResMut<ThrustInput>,
+    sun: Single<&Sun>,
+    player: Single<(&ItemPosition, &ShipStats, &PlayerShield), With<Player>>,
+    threats: Query<
+        (&Transform, Option<&LinearVelocity>, Has<BlackHole>),
+        Or<(With<Obstacle>, (With<BlackHole>, With<Collider>))>,
+    >,
+) {
+    let (position, stats, shield) = player.into_inner();
+
+    // measure the angular speed directly so boosts and skimming are included
+    let angular_speed = match state.last_theta.replace(position.theta) {
+        Some(last) if time.delta_secs() > 0.0 => (position.theta - last) / time.delta_secs(),
+        _ => 0.0,
+    };
+
+    let threats: Vec<Threat> = threats
+        .iter()
+        .filter_map(|(tx, velocity, black_hole)| {
+            let approach = threats::approach(
+                angular_speed,
+                position,
+                sun.radius,
+                tx.translation.truncate(),
+                velocity.map(|v| v.0).unwrap_or_default(),
+            )?;
+            let clearance = if black_hole {
+                BLACK_HOLE_CLEARANCE
+            } else {
+                OBSTACLE_CLEARANCE
+            };
+            (approach.time <= LOOKAHEAD).then_some(Threat {
+                approach,
+                clearance,
+            })
+        })
+        .collect();
+
+    let course = Course {
+        radius: position.radius,
+        climb_speed: stats.radius_change_speed,
+        threats: &threats,
+    };
+
+    let goal = if autopilot.is_some() {
+        match **nova {
+            Nova::Idle => {
+                let shield = shield.current_frac();
+                if shield < RECHARGE_BELOW {
+                    state.recharging = true;
+                } else if shield > SKIM_ABOVE {
+                    state.recharging = false;
+                }
+
+                if state.recharging {
+                    RECHARGE_RADIUS
+                } else {
+                    0.0
+                }
+            }
+            // black holes only appear close to the sun, so wait them out
+            // far away
+            _ => MAX_PLAYER_RADIUS,
+        }
+    } else {
+        // the player keeps control unless they are about to hit something
+        let heading = if thrust.0 { MAX_PLAYER_RADIUS } else { 0.0 };
+        if course.danger(heading, ASSIST_LOOKAHEAD) == 0.0 {
+            return;
+        }
+        heading
+    };
+
+    let target = course.best_target(goal);
+    thrust.0 = course.input_towards(target) > input.0;
+}
+
+/// The ship's options for changing radius, given the threats ahead
+struct Course<'a> {
+    radius: f32,
+    climb_speed: f32,
+    threats: &'a [Threat],
+}
+
+impl Course<'_> {
+    /// Where the ship would be after `time` seconds heading for `target` as
+    /// quickly as it can
+    fn radius_after(&self, target: f32, time: f32) -> f32 {
+        let reach = self.climb_speed * (time - RESPONSE_TIME).max(0.0);
+        self.radius + (target - self.radius).clamp(-reach, reach)
+    }
+
+    /// How bad the collisions heading for `target` would be, counting the
+    /// sooner ones as worse as there is less time to get out of them
+    fn danger(&self, target: f32, lookahead: f32) -> f32 {
+        self.threats
+            .iter()
+            .filter(|threat| threat.approach.time <= lookahead)
+            .filter(|threat| {
+                let radius = self.radius_after(target, threat.approach.time);
+                (radius - threat.approach.radius).abs() < threat.clearance
+            })
+            .map(|threat| 1.0 + lookahead - threat.approach.time)
+            .sum()
+    }
+
+    /// The radius to head for that avoids the threats ahead, staying as
+    /// close to the goal as it can
+    fn best_target(&self, goal: f32) -> f32 {
+        let steps = (MAX_PLAYER_RADIUS / RADIUS_STEP) as u32;
+        (0..=steps)
+            .map(|step| step as f32 * RADIUS_STEP)
+            .map(|target| {
+                let cost = self.danger(target, LOOKAHEAD) * HIT_COST + (target - goal).abs();
+                (target, cost)
+            })
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map_or(goal, |(target, _)| target)
+    }
+
+    /// The [`PlayerInputAngle`] that takes the ship to `target`, slowing down
+    /// on the way in unless it is at either edge
+    fn input_towards(&self, target: f32) -> f32 {
+        if target <= 0.0 {
+            -1.0
+        } else if target >= MAX_PLAYER_RADIUS {
+            1.0
+        } else {
+            ((target - self.radius) / SLOWDOWN_DISTANCE).clamp(-1.0, 1.0)
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//! Plays many headless runs with scripted pilots and prints how they went, to
+//! judge balance changes without playtesting.
+//!
+//! ```text
+//! cargo run --release --bin simulate -- --runs 1000 --policy hug-sun --policy hold-radius=50
+//! ```
+
+use std::{collections::BTreeMap, process::ExitCode, time::Duration};
+
+use nova_looper::simulation::{Policy, RunSummary, Simulation};
+
+const USAGE: &str = "\
+Usage: simulate [options]
+
+Options:
+  --runs <n>          Runs per policy, each with its own seed (default 100)
+  --first-seed <n>    The seed of the first run, counting up from there (default 0)
+  --policy <name>     hug-sun, hold-radius[=<radius>], random-thrust or
+                      autopilot. Can be repeated, and defaults to all four
+  --max-time <secs>   Ends runs that are still going after this long (default 600)
+  --timestep <secs>   Game time simulated by each update (default 1/60)";
+
+struct Options {
+    runs: u64,
+    first_seed: u64,
+    policies: Vec<Policy>,
+    max_time: f32,
+    timestep: f32,
+}
+
+impl Options {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut options = Options {
+            runs: 100,
+            first_seed: 0,
+            policies: Vec::new(),
+            max_time: 600.0,
+            timestep: 1.0 / 60.0,
+        };
+
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("{arg} needs a value"));
+            match arg.as_str() {
+                "--runs" => options.runs = parse_number(&arg, &value()?)?,
+                "--first-seed" => options.first_seed = parse_number(&arg, &value()?)?,
+                "--max-time" => options.max_time = parse_number(&arg, &value()?)?,
+                "--timestep" => options.timestep = parse_number(&arg, &value()?)?,
+                "--policy" => {
+                    let name = value()?;
+                    let policy = Policy::parse(&name).ok_or(format!("unknown policy {name}"))?;
+                    options.policies.push(policy);
+                }
+                _ => return Err(format!("unknown option {arg}")),
+            }
+        }
+
+        if options.policies.is_empty() {
+            options.policies = vec![
+                Policy::HugSun,
+                Policy::HoldRadius(50.0),
+                Policy::RandomThrust,
+                Policy::Autopilot,
+            ];
+        }
+        if options.timestep <= 0.0 {
+            return Err("--timestep must be positive".into());
+        }
+
+        Ok(options)
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(option: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("{option} expects a number, not {value}"))
+}
+
+fn main() -> ExitCode {
+    let options = match Options::parse(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("{err}\n\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut simulation = Simulation::new(Duration::from_secs_f32(options.timestep));
+
+    for policy in &options.policies {
+        let runs: Vec<RunSummary> = (0..options.runs)
+            .map(|i| simulation.run(options.first_seed + i, *policy, options.max_time))
+            .collect();
+        print_report(*policy, &runs);
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn print_report(policy: Policy, runs: &[RunSummary]) {
+    println!("== {policy} ({} runs) ==", runs.len());
+    if runs.is_empty() {
+        println!();
+        return;
+    }
+
+    print_distribution("score", runs.iter().map(|run| run.score));
+    print_distribution(
+        "survival time (s)",
+        runs.iter().map(|run| run.survival_time),
+    );
+    print_distribution(
+        "novas survived",
+        runs.iter().map(|run| run.novas_survived as f32),
+    );
+
+    let mut reasons = BTreeMap::new();
+    for run in runs {
+        *reasons
+            .entry(run.death_reason.unwrap_or("still alive"))
+            .or_insert(0) += 1;
+    }
+    println!("death reasons:");
+    for (reason, count) in reasons {
+        let percent = 100.0 * count as f32 / runs.len() as f32;
+        println!("  {reason:<20} {count:>6} ({percent:.1}%)");
+    }
+    println!();
+}
+
+/// Prints the mean and the quartiles of the values
+fn print_distribution(name: &str, values: impl Iterator<Item = f32>) {
+    let mut values: Vec<f32> = values.collect();
+    values.sort_by(f32::total_cmp);
+
+    let quantile = |q: f32| values[((values.len() - 1) as f32 * q).round() as usize];
+    let mean = values.iter().sum::<f32>() / values.len() as f32;
+
+    println!(
+        "{name:<18} mean {mean:>9.1} | min {:>9.1}  p25 {:>9.1}  median {:>9.1}  p75 {:>9.1}  max {:>9.1}",
+        quantile(0.0),
+        quantile(0.25),
+        quantile(0.5),
+        quantile(0.75),
+        quantile(1.0),
+    );
+}
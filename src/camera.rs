@@ -0,0 +1,205 @@
+//! The gameplay camera, which follows the player with optional zoom, rotation,
+//! screen shake and look-ahead. Each behaviour can be turned off in the
+//! settings for motion-sensitive players.
+
+use std::f32::consts::FRAC_PI_2;
+
+use bevy::{core_pipeline::bloom::Bloom, prelude::*};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    obstacle::PlayerCollision,
+    player::{ItemPosition, Player},
+    save::SaveData,
+    score::Score,
+    screens::Screen,
+    supernova::Nova,
+    utils::get_player_speed_multipliers,
+};
+
+/// The furthest the camera zooms out at high speed
+const MAX_ZOOM: f32 = 1.6;
+/// How much the zoom increases with each unit of speed above the base speed
+const ZOOM_PER_SPEED: f32 = 0.35;
+/// How far ahead of the player the camera looks at the base speed
+const LOOK_AHEAD_DISTANCE: f32 = 80.0;
+/// How quickly the zoom and look-ahead catch up with their targets
+const SMOOTHING: f32 = 3.0;
+/// The furthest the camera is moved by a full strength shake
+const MAX_SHAKE_OFFSET: f32 = 18.0;
+/// Shake strength lost per second
+const SHAKE_DECAY: f32 = 1.5;
+/// Shake strength added by hitting something
+const HIT_SHAKE: f32 = 0.6;
+/// The minimum shake strength while the nova builds up
+const BUILDUP_SHAKE: f32 = 0.35;
+/// The bloom intensity used when flashing is reduced
+const REDUCED_BLOOM_INTENSITY: f32 = 0.05;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<CameraSettings>();
+    app.register_type::<CameraState>();
+
+    app.init_resource::<CameraState>();
+
+    app.add_systems(OnEnter(Screen::Gameplay), reset_camera_state);
+    app.add_systems(Update, add_camera_shake.run_if(in_state(Screen::Gameplay)));
+    app.add_systems(
+        PostUpdate,
+        update_camera
+            .before(TransformSystem::TransformPropagate)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+    app.add_systems(OnExit(Screen::Gameplay), reset_camera);
+    app.add_systems(Update, apply_bloom.run_if(resource_changed::<SaveData>));
+}
+
+/// Which camera behaviours are turned on, stored with the save data
+#[derive(Reflect, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(default)]
+pub struct CameraSettings {
+    /// Zoom out as the player speeds up
+    pub zoom: bool,
+    /// Rotate so the sun is always at the bottom of the screen
+    pub rotate: bool,
+    /// Shake on hits and while the nova builds up
+    pub shake: bool,
+    /// Lead the player in the direction of travel
+    pub look_ahead: bool,
+}
+
+impl Default for CameraSettings {
+    fn default() -> Self {
+        Self {
+            zoom: true,
+            rotate: false,
+            shake: true,
+            look_ahead: true,
+        }
+    }
+}
+
+/// The smoothed state of the camera behaviours
+#[derive(Resource, Reflect, Debug)]
+#[reflect(Resource)]
+pub struct CameraState {
+    pub zoom: f32,
+    pub look_ahead: f32,
+    /// The current shake strength, between 0 and 1
+    pub shake: f32,
+}
+
+impl Default for CameraState {
+    fn default() -> Self {
+        Self {
+            zoom: 1.0,
+            look_ahead: 0.0,
+            shake: 0.0,
+        }
+    }
+}
+
+fn reset_camera_state(mut state: ResMut<CameraState>) {
+    *state = CameraState::default();
+}
+
+fn add_camera_shake(
+    time: Res<Time>,
+    nova: Res<State<Nova>>,
+    mut state: ResMut<CameraState>,
+    mut collisions: EventReader<PlayerCollision>,
+) {
+    state.shake = (state.shake - SHAKE_DECAY * time.delta_secs()).max(0.0);
+
+    for _ in collisions.read() {
+        state.shake = (state.shake + HIT_SHAKE).min(1.0);
+    }
+
+    if **nova == Nova::BuildingUp {
+        state.shake = state.shake.max(BUILDUP_SHAKE);
+    }
+}
+
+pub fn update_camera(
+    time: Res<Time>,
+    save: Res<SaveData>,
+    score: Option<Res<Score>>,
+    nova: Res<State<Nova>>,
+    mut state: ResMut<CameraState>,
+    camera: Single<(&mut Transform, &mut Projection), (With<Camera2d>, Without<Player>)>,
+    player: Single<(&Transform, &ItemPosition), With<Player>>,
+) {
+    let settings = save.camera;
+    let (mut camera_tx, mut projection) = camera.into_inner();
+    let (player_tx, position) = player.into_inner();
+
+    let multiplier = score.map(|score| score.multiplier).unwrap_or_default();
+    let (speed, level) = get_player_speed_multipliers(multiplier, position, **nova);
+    let speed = speed + level;
+
+    let smoothing = (SMOOTHING * time.delta_secs()).min(1.0);
+    let target_zoom = if settings.zoom {
+        (1.0 + ZOOM_PER_SPEED * (speed - 1.0)).clamp(1.0, MAX_ZOOM)
+    } else {
+        1.0
+    };
+    state.zoom += (target_zoom - state.zoom) * smoothing;
+
+    let target_look_ahead = if settings.look_ahead {
+        LOOK_AHEAD_DISTANCE * speed
+    } else {
+        0.0
+    };
+    state.look_ahead += (target_look_ahead - state.look_ahead) * smoothing;
+
+    if let Projection::Orthographic(orthographic) = &mut *projection {
+        orthographic.scale = state.zoom;
+    }
+
+    // the player moves clockwise around the sun
+    let theta = position.theta;
+    let forward = Vec2::new(theta.cos(), -theta.sin());
+
+    let shake = if settings.shake {
+        let t = time.elapsed_secs();
+        let noise = Vec2::new(
+            (t * 47.0).sin() + (t * 31.0).cos(),
+            (t * 53.0).cos() + (t * 29.0).sin(),
+        );
+        noise * 0.5 * MAX_SHAKE_OFFSET * state.shake * state.shake
+    } else {
+        Vec2::ZERO
+    };
+
+    let target = player_tx.translation.truncate() + forward * state.look_ahead + shake;
+    camera_tx.translation = target.extend(camera_tx.translation.z);
+
+    camera_tx.rotation = if settings.rotate {
+        // keep the sun at the bottom of the screen
+        Quat::from_rotation_z(player_tx.translation.truncate().to_angle() - FRAC_PI_2)
+    } else {
+        Quat::IDENTITY
+    };
+}
+
+fn reset_camera(mut camera: Single<(&mut Transform, &mut Projection), With<Camera2d>>) {
+    let (tx, projection) = &mut *camera;
+    tx.translation = Vec3::new(0.0, 0.0, tx.translation.z);
+    tx.rotation = Quat::IDENTITY;
+
+    if let Projection::Orthographic(orthographic) = &mut **projection {
+        orthographic.scale = 1.0;
+    }
+}
+
+fn apply_bloom(save: Res<SaveData>, mut blooms: Query<&mut Bloom>) {
+    let intensity = if save.reduce_flashing {
+        REDUCED_BLOOM_INTENSITY
+    } else {
+        Bloom::default().intensity
+    };
+
+    for mut bloom in &mut blooms {
+        bloom.intensity = intensity;
+    }
+}
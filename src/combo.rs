@@ -0,0 +1,106 @@
+//! The skimming combo, which builds while the player stays on the sun's
+//! surface and boosts their score income.
+
+use bevy::prelude::*;
+use bevy_seedling::prelude::{Notify, PlaybackSettings, PlaybackState, SamplePlayer, Volume};
+
+use crate::{
+    MusicAssets,
+    materials::BarDataSource,
+    player::{ItemPosition, Player},
+    screens::Screen,
+    supernova::Nova,
+};
+
+/// The combo at which the meter is full
+const MAX_COMBO: f32 = 100.0;
+/// Combo gained per second of continuous skimming
+const COMBO_BUILD_RATE: f32 = 12.0;
+/// Combo lost per second away from the surface
+const COMBO_DECAY_RATE: f32 = 30.0;
+/// The extra score multiplier at a full combo
+const MAX_COMBO_SCORE_BONUS: f32 = 1.0;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<SkimCombo>();
+    app.register_type::<SkimmingSound>();
+
+    app.add_systems(OnEnter(Screen::Gameplay), spawn_skimming_sound);
+    app.add_systems(
+        Update,
+        (update_combo, update_skimming_sound)
+            .chain()
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+#[derive(Component, Reflect, Debug, Default)]
+#[reflect(Component)]
+pub struct SkimCombo(pub f32);
+
+impl SkimCombo {
+    /// The multiplier applied to score income
+    pub fn score_multiplier(&self) -> f32 {
+        1.0 + MAX_COMBO_SCORE_BONUS * self.current_frac()
+    }
+}
+
+impl BarDataSource for SkimCombo {
+    fn current_frac(&self) -> f32 {
+        (self.0 / MAX_COMBO).clamp(0.0, 1.0)
+    }
+}
+
+/// The looping skimming sound, which rises in pitch with the combo
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct SkimmingSound;
+
+fn spawn_skimming_sound(mut commands: Commands, music: Res<MusicAssets>) {
+    commands.spawn((
+        SamplePlayer::new(music.skimming_sun.clone())
+            .looping()
+            .with_volume(Volume::Linear(0.5)),
+        PlaybackSettings {
+            playback: Notify::new(PlaybackState::Pause),
+            ..default()
+        },
+        SkimmingSound,
+        StateScoped(Screen::Gameplay),
+    ));
+}
+
+fn update_combo(
+    time: Res<Time>,
+    nova: Res<State<Nova>>,
+    player: Single<(&ItemPosition, &mut SkimCombo), With<Player>>,
+) {
+    let (position, mut combo) = player.into_inner();
+
+    let skimming = matches!(**nova, Nova::Idle) && position.radius < 1.0;
+    let change = if skimming {
+        COMBO_BUILD_RATE
+    } else {
+        -COMBO_DECAY_RATE
+    };
+
+    combo.0 = (combo.0 + change * time.delta_secs()).clamp(0.0, MAX_COMBO);
+}
+
+fn update_skimming_sound(
+    nova: Res<State<Nova>>,
+    player: Single<(&ItemPosition, &SkimCombo), With<Player>>,
+    mut sound: Single<&mut PlaybackSettings, With<SkimmingSound>>,
+) {
+    let (position, combo) = player.into_inner();
+    let skimming = matches!(**nova, Nova::Idle) && position.radius < 1.0;
+
+    let playing = matches!(*sound.playback, PlaybackState::Play { .. });
+    if skimming && !playing {
+        sound.play();
+    } else if !skimming && playing {
+        sound.pause();
+    }
+
+    sound.speed = 1.0 + combo.current_frac() as f64;
+}
@@ -1,38 +1,51 @@
-use bevy::color::Color;
-
-// The clear colour
-pub const SPLASH_BACKGROUND_COLOR: Color = Color::srgb(0.057, 0.057, 0.057);
-
-/// The speed the player starts with
-pub const PLAYER_STARTING_SPEED: f32 = 0.45;
-
-/// The radius of the sun at the start
-pub const SUN_STARTING_RADIUS: f32 = 1000.0;
-
-/// The random fudge factor for movement speed inwards and outwards
-/// along the radius
-pub const PLAYER_RADIUS_CHANGE_SPEED: f32 = 180.0;
-
-/// The random fudge factor that makes controls feel more responsive.
-/// This controls how fast the movement direction changes
-pub const MAGIC_MOVEMENT_ACCEL_SCALE: f32 = 14.0;
-
-/// The maximum radius a player can have relateive the centre of the sun
-pub const MAX_PLAYER_RADIUS: f32 = 800.0;
-
-/// The main theme colour
-pub const MAIN_THEME_COLOR: Color = Color::hsla(21.0, 0.936, 0.51, 1.0);
-
-/// The colour of the middle of the sun
-pub const INNER_SUN_COLOUR: Color = Color::srgba(3.968, 0.372, 0.051, 1.0);
-/// The colour of the sun
-pub const SUN_COLOUR: Color = Color::srgba(2.868, 0.602, 0.061, 1.0);
-
-/// The rate the score increases each second
-pub const SCORE_INCREASE_RATE: f32 = 7.0;
-
-/// The cost to shields of hitting an obstacle
-pub const SHIELD_COST_ON_OBSTACLE_HIT: f32 = 30.0;
-
-/// The colour of sun flare obstacles
-pub const OBSTACLE_COLOR: Color = Color::srgba(6.0, 1.7, 0.08, 1.0);
+use bevy::color::Color;
+
+// The clear colour
+pub const SPLASH_BACKGROUND_COLOR: Color = Color::srgb(0.057, 0.057, 0.057);
+
+/// The speed the player starts with
+pub const PLAYER_STARTING_SPEED: f32 = 0.45;
+
+/// The radius of the sun at the start
+pub const SUN_STARTING_RADIUS: f32 = 1000.0;
+
+/// The random fudge factor for movement speed inwards and outwards
+/// along the radius
+pub const PLAYER_RADIUS_CHANGE_SPEED: f32 = 180.0;
+
+/// The random fudge factor that makes controls feel more responsive.
+/// This controls how fast the movement direction changes
+pub const MAGIC_MOVEMENT_ACCEL_SCALE: f32 = 14.0;
+
+/// The maximum radius a player can have relateive the centre of the sun
+pub const MAX_PLAYER_RADIUS: f32 = 800.0;
+
+/// The main theme colour
+pub const MAIN_THEME_COLOR: Color = Color::hsla(21.0, 0.936, 0.51, 1.0);
+
+/// The colour of the middle of the sun
+pub const INNER_SUN_COLOUR: Color = Color::srgba(3.968, 0.372, 0.051, 1.0);
+/// The colour of the sun
+pub const SUN_COLOUR: Color = Color::srgba(2.868, 0.602, 0.061, 1.0);
+
+/// The rate the score increases each second
+pub const SCORE_INCREASE_RATE: f32 = 7.0;
+
+/// The shield the player starts with, before upgrades
+pub const PLAYER_MAX_SHIELD: f32 = 100.0;
+
+/// The cost to shields of hitting an obstacle
+pub const SHIELD_COST_ON_OBSTACLE_HIT: f32 = 30.0;
+
+/// The colour the screen flashes to when the sun goes nova
+pub const BLOOMED_WHITE: Color = Color::srgba(3.0, 3.0, 3.0, 0.0);
+
+/// The colour of sun flare obstacles
+pub const OBSTACLE_COLOR: Color = Color::srgba(6.0, 1.7, 0.08, 1.0);
+
+/// How many frames back the ship's trail reaches
+pub const PLAYER_TRAIL_LENGTH: usize = 16;
+/// How long each point of the ship's trail takes to fade out
+pub const PLAYER_TRAIL_LIFETIME: f32 = 0.25;
+/// The colour of the ship's trail
+pub const PLAYER_TRAIL_COLOR: Color = Color::srgba(3.0, 4.0, 4.0, 1.0);
@@ -0,0 +1,108 @@
+//! The daily challenge, a run seeded from the current UTC date so that
+//! everybody playing on the same day gets the same layout.
+
+use bevy::prelude::*;
+use web_time::{SystemTime, UNIX_EPOCH};
+
+use crate::{locale::Locale, save::SaveData, score::Score, screens::Screen, utils::format_number};
+
+const SECONDS_PER_DAY: u64 = 60 * 60 * 24;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<DailyChallenge>();
+
+    app.add_systems(
+        OnEnter(Screen::Gameplay),
+        record_daily_attempt.run_if(resource_exists::<DailyChallenge>),
+    );
+    app.add_systems(
+        OnExit(Screen::Gameplay),
+        record_daily_best.run_if(resource_exists::<DailyChallenge>),
+    );
+}
+
+/// Present while the current run is a daily challenge.
+#[derive(Resource, Reflect, Debug, Clone, Copy)]
+#[reflect(Resource)]
+pub struct DailyChallenge {
+    /// The number of days since the unix epoch, also used as the run seed
+    pub day: u64,
+}
+
+impl DailyChallenge {
+    pub fn today() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+
+        Self {
+            day: seconds / SECONDS_PER_DAY,
+        }
+    }
+
+    /// Formats the day as an ISO 8601 date, e.g. `2025-08-01`
+    pub fn date(&self) -> String {
+        // Howard Hinnant's `civil_from_days`
+        let z = self.day as i64 + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z - era * 146_097;
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + i64::from(month <= 2);
+
+        format!("{year:04}-{month:02}-{day:02}")
+    }
+
+    /// A short description of today's progress, for showing in menus
+    pub fn status(&self, save: &SaveData, locale: &Locale) -> String {
+        let date = self.date();
+        match save.daily_bests.get(&self.day) {
+            Some(best) => locale.format(
+                "daily.best",
+                &[
+                    ("date", &date),
+                    ("score", &format_number(*best, locale.language())),
+                ],
+            ),
+            None => locale.format("daily.not_attempted", &[("date", &date)]),
+        }
+    }
+}
+
+fn record_daily_attempt(daily: Res<DailyChallenge>, mut save: ResMut<SaveData>) {
+    save.daily_bests.entry(daily.day).or_default();
+}
+
+fn record_daily_best(
+    daily: Res<DailyChallenge>,
+    score: Option<Res<Score>>,
+    mut save: ResMut<SaveData>,
+) {
+    let Some(score) = score else {
+        return;
+    };
+
+    let best = save.daily_bests.entry(daily.day).or_default();
+    *best = best.max(score.score);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dates_are_formatted_from_the_day() {
+        let date = |day| DailyChallenge { day }.date();
+
+        assert_eq!(date(0), "1970-01-01");
+        assert_eq!(date(19_782), "2024-02-29");
+        assert_eq!(date(19_783), "2024-03-01");
+        // 2000 is a leap year despite being a century
+        assert_eq!(date(11_016), "2000-02-29");
+        assert_eq!(date(11_017), "2000-03-01");
+    }
+}
@@ -0,0 +1,150 @@
+//! Gravity that pulls towards the sun, instead of avian's downwards gravity.
+//!
+//! Every dynamic body is pulled, scaled by its [`GravityScale`], and burns up
+//! when it falls into the sun. The particles of any spawner with a
+//! [`GravityScale`] fall towards the sun too, fading at its surface.
+
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use bevy_enoki::{
+    Particle2dEffect, ParticleEffectHandle,
+    prelude::{ParticleEffectInstance, ParticleSpawnerState, Rval},
+};
+
+use crate::sun::Sun;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<SunGravity>();
+
+    app.insert_resource(Gravity::ZERO);
+    app.init_resource::<SunGravity>();
+
+    app.add_systems(
+        FixedPostUpdate,
+        (pull_bodies, burn_up_bodies)
+            .chain()
+            .before(PhysicsSet::StepSimulation),
+    );
+    app.add_observer(hold_particles);
+    app.add_systems(
+        PostUpdate,
+        aim_particles.after(TransformSystem::TransformPropagate),
+    );
+}
+
+/// How strongly the sun pulls things towards it
+#[derive(Resource, Reflect, Debug)]
+#[reflect(Resource)]
+pub struct SunGravity {
+    /// The acceleration towards the centre of the sun, in pixels per second
+    /// squared
+    pub strength: f32,
+}
+
+impl Default for SunGravity {
+    fn default() -> Self {
+        Self { strength: 2.0 }
+    }
+}
+
+/// The centre and current radius of the sun, which grows and shrinks with
+/// the nova
+fn sun_surface(sun: &Sun, transform: &GlobalTransform) -> (Vec2, f32) {
+    let (scale, _, centre) = transform.to_scale_rotation_translation();
+    (centre.truncate(), sun.radius * scale.x)
+}
+
+fn pull_bodies(
+    time: Res<Time>,
+    gravity: Res<SunGravity>,
+    sun: Single<&GlobalTransform, With<Sun>>,
+    mut bodies: Query<(
+        &RigidBody,
+        &Position,
+        &mut LinearVelocity,
+        Option<&GravityScale>,
+    )>,
+) {
+    let centre = sun.translation().truncate();
+    let pull = gravity.strength * time.delta_secs();
+
+    for (body, position, mut velocity, scale) in &mut bodies {
+        if !body.is_dynamic() {
+            continue;
+        }
+        let scale = scale.map_or(1.0, |scale| scale.0);
+        velocity.0 += (centre - position.0).normalize_or_zero() * pull * scale;
+    }
+}
+
+/// Despawns the bodies falling through the sun's surface. Those rising out of
+/// it, like freshly spawned flares, are left alone.
+fn burn_up_bodies(
+    mut commands: Commands,
+    sun: Single<(&Sun, &GlobalTransform)>,
+    bodies: Query<(Entity, &RigidBody, &Position, &LinearVelocity)>,
+) {
+    let (centre, radius) = sun_surface(sun.0, sun.1);
+
+    for (entity, body, position, velocity) in &bodies {
+        let outwards = position.0 - centre;
+        if body.is_dynamic() && outwards.length() < radius && outwards.dot(velocity.0) < 0.0 {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Holds back the first burst of a spawner given a [`GravityScale`] until
+/// [`aim_particles`] has pointed it at the sun. Pooled spawners are moved
+/// just before they play, so their last aim would be wrong.
+fn hold_particles(
+    trigger: Trigger<OnInsert, GravityScale>,
+    mut spawners: Query<&mut ParticleSpawnerState>,
+) {
+    if let Ok(mut state) = spawners.get_mut(trigger.target()) {
+        state.timer.pause();
+    }
+}
+
+/// Points the gravity of spawners with a [`GravityScale`] at the sun, and
+/// shortens their particles' lives so that they fade on reaching its surface.
+///
+/// enoki's gravity is a steady drift rather than an acceleration, so the
+/// particles fall at the sun's strength times their scale, in pixels per
+/// second.
+fn aim_particles(
+    gravity: Res<SunGravity>,
+    sun: Single<(&Sun, &GlobalTransform)>,
+    effects: Res<Assets<Particle2dEffect>>,
+    mut spawners: Query<(
+        &mut ParticleEffectInstance,
+        &mut ParticleSpawnerState,
+        &ParticleEffectHandle,
+        &GlobalTransform,
+        &GravityScale,
+    )>,
+) {
+    let (centre, radius) = sun_surface(sun.0, sun.1);
+
+    for (mut instance, mut state, handle, transform, scale) in &mut spawners {
+        // enoki copies the effect into the instance a frame after spawning
+        let (Some(instance), Some(effect)) = (instance.0.as_mut(), effects.get(&handle.0)) else {
+            continue;
+        };
+
+        let towards = centre - transform.translation().truncate();
+        let speed = gravity.strength * scale.0;
+        instance.gravity_direction = Some(Rval(towards.normalize_or_zero(), 0.0));
+        instance.gravity_speed = Some(Rval(speed, 0.0));
+        instance.lifetime.0 = if speed > 0.0 {
+            effect
+                .lifetime
+                .0
+                .min((towards.length() - radius).max(0.0) / speed)
+        } else {
+            effect.lifetime.0
+        };
+
+        state.timer.unpause();
+    }
+}
@@ -1,50 +1,77 @@
-use bevy::prelude::*;
-
-use crate::{
-    consts::{MAGIC_MOVEMENT_ACCEL_SCALE, MAX_PLAYER_RADIUS, PLAYER_RADIUS_CHANGE_SPEED},
-    player::{ItemPosition, Player},
-    screens::Screen,
-    supernova::Nova,
-};
-
-pub(super) fn plugin(app: &mut App) {
-    app.add_systems(Update, control_player.run_if(in_state(Screen::Gameplay)));
-    app.init_resource::<PlayerInputAngle>();
-}
-
-#[derive(Resource, Reflect, Debug, Default)]
-#[reflect(Resource)]
-pub struct PlayerInputAngle(pub f32);
-
-fn control_player(
-    nova: Res<State<Nova>>,
-    mut delta: ResMut<PlayerInputAngle>,
-    keyboard_input: Res<ButtonInput<KeyCode>>,
-    touches: Res<Touches>,
-    mouse: Res<ButtonInput<MouseButton>>,
-    time: Res<Time>,
-    mut player: Single<&mut ItemPosition, With<Player>>,
-) {
-    match **nova {
-        Nova::Idle | Nova::During => {
-            if keyboard_input.pressed(KeyCode::Space)
-                || touches.iter().next().is_some()
-                || mouse.pressed(MouseButton::Left)
-            {
-                delta.0 += MAGIC_MOVEMENT_ACCEL_SCALE * time.delta_secs();
-            } else {
-                delta.0 -= MAGIC_MOVEMENT_ACCEL_SCALE * time.delta_secs();
-            }
-            delta.0 = delta.0.clamp(-1.0, 1.0);
-        }
-        Nova::BuildingUp => {
-            delta.0 = 1.0;
-        }
-        Nova::After => {
-            delta.0 = -1.0;
-        }
-    };
-
-    player.radius = (player.radius + delta.0 * time.delta_secs() * PLAYER_RADIUS_CHANGE_SPEED)
-        .clamp(0.5, MAX_PLAYER_RADIUS);
-}
+use bevy::prelude::*;
+
+use crate::{
+    AppSystems,
+    consts::{MAGIC_MOVEMENT_ACCEL_SCALE, MAX_PLAYER_RADIUS},
+    player::{ItemPosition, Player, ShipStats},
+    screens::Screen,
+    supernova::Nova,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<PlayerInputAngle>();
+    app.register_type::<ThrustInput>();
+
+    app.init_resource::<PlayerInputAngle>();
+    app.init_resource::<ThrustInput>();
+
+    app.add_systems(
+        Update,
+        (
+            record_thrust.in_set(AppSystems::RecordInput),
+            control_player.in_set(AppSystems::Update),
+        )
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+#[derive(Resource, Reflect, Debug, Default)]
+#[reflect(Resource)]
+pub struct PlayerInputAngle(pub f32);
+
+/// Whether the ship is thrusting away from the sun this frame. Anything else
+/// flying the ship overwrites it after [`record_thrust`].
+#[derive(Resource, Reflect, Debug, Default)]
+#[reflect(Resource)]
+pub struct ThrustInput(pub bool);
+
+pub fn record_thrust(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    touches: Res<Touches>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut thrust: ResMut<ThrustInput>,
+) {
+    thrust.0 = keyboard_input.pressed(KeyCode::Space)
+        || touches.iter().next().is_some()
+        || mouse.pressed(MouseButton::Left);
+}
+
+fn control_player(
+    nova: Res<State<Nova>>,
+    mut delta: ResMut<PlayerInputAngle>,
+    thrust: Res<ThrustInput>,
+    time: Res<Time>,
+    player: Single<(&mut ItemPosition, &ShipStats), With<Player>>,
+) {
+    let (mut player, stats) = player.into_inner();
+
+    match **nova {
+        Nova::Idle | Nova::During => {
+            if thrust.0 {
+                delta.0 += MAGIC_MOVEMENT_ACCEL_SCALE * time.delta_secs();
+            } else {
+                delta.0 -= MAGIC_MOVEMENT_ACCEL_SCALE * time.delta_secs();
+            }
+            delta.0 = delta.0.clamp(-1.0, 1.0);
+        }
+        Nova::BuildingUp => {
+            delta.0 = 1.0;
+        }
+        Nova::After => {
+            delta.0 = -1.0;
+        }
+    };
+
+    player.radius = (player.radius + delta.0 * time.delta_secs() * stats.radius_change_speed)
+        .clamp(0.5, MAX_PLAYER_RADIUS);
+}
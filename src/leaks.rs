@@ -0,0 +1,105 @@
+//! A leak auditor for debug builds. Every time a run returns to the title
+//! screen it counts the entities with each set of components, and warns about any set
+//! that grew since the last time, as that usually means something spawned
+//! during a run wasn't scoped to it.
+//!
+//! The audio engine's own nodes, which its pools add as they need them,
+//! one-shot sounds, which despawn once they finish, and systems registered
+//! on first use are left out.
+
+use std::collections::BTreeMap;
+
+use bevy::{ecs::system::SystemIdMarker, prelude::*};
+use bevy_seedling::prelude::{
+    FirewheelNode, OnComplete, PlaybackSettings, RepeatMode, SamplePlayer,
+};
+
+use crate::{mode::GameMode, screens::Screen};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<LastCensus>();
+
+    // only after the player's runs, as the menu is still open when an attract
+    // run comes back through the title screen
+    app.add_systems(
+        OnEnter(Screen::Title),
+        audit_entities.run_if(not(resource_equals(GameMode::Attract))),
+    );
+}
+
+/// The number of entities with each set of components, keyed by the sorted
+/// component names
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct EntityCensus(pub BTreeMap<String, usize>);
+
+impl EntityCensus {
+    pub fn take(world: &World) -> Self {
+        let ignored = [
+            world.component_id::<FirewheelNode>(),
+            world.component_id::<SystemIdMarker>(),
+        ];
+
+        let mut counts = BTreeMap::new();
+        for archetype in world.archetypes().iter().filter(|a| !a.is_empty()) {
+            if ignored.iter().flatten().any(|&id| archetype.contains(id)) {
+                continue;
+            }
+
+            let count = archetype
+                .entities()
+                .iter()
+                .filter(|entity| !is_one_shot_sound(world.entity(entity.id())))
+                .count();
+            if count == 0 {
+                continue;
+            }
+
+            let mut names: Vec<_> = archetype
+                .components()
+                .filter_map(|id| world.components().get_name(id))
+                .collect();
+            names.sort();
+            *counts.entry(names.join(", ")).or_default() += count;
+        }
+        Self(counts)
+    }
+
+    /// Each set of components with more entities than in `earlier`, with the
+    /// counts before and after
+    pub fn growth_since<'a>(
+        &'a self,
+        earlier: &EntityCensus,
+    ) -> impl Iterator<Item = (&'a str, usize, usize)> {
+        self.0.iter().filter_map(|(components, &count)| {
+            let before = earlier.0.get(components).copied().unwrap_or_default();
+            (count > before).then_some((components.as_str(), before, count))
+        })
+    }
+}
+
+fn is_one_shot_sound(entity: EntityRef) -> bool {
+    let (Some(player), Some(settings)) = (
+        entity.get::<SamplePlayer>(),
+        entity.get::<PlaybackSettings>(),
+    ) else {
+        return false;
+    };
+    player.repeat_mode != RepeatMode::RepeatEndlessly
+        && matches!(settings.on_complete, OnComplete::Despawn)
+}
+
+/// The census taken the last time the title screen was entered
+#[derive(Resource, Debug, Default)]
+pub struct LastCensus(pub Option<EntityCensus>);
+
+fn audit_entities(world: &mut World) {
+    let census = EntityCensus::take(world);
+    let mut last = world.resource_mut::<LastCensus>();
+
+    if let Some(earlier) = &last.0 {
+        for (components, before, after) in census.growth_since(earlier) {
+            warn!("{after} entities with [{components}], up from {before} after the last run");
+        }
+    }
+    last.0 = Some(census);
+}
@@ -0,0 +1,246 @@
+// Support configuring Bevy lints within code.
+#![cfg_attr(bevy_lint, feature(register_tool), register_tool(bevy))]
+// The `AsBindGroup` derive exceeds the default limit on newer toolchains.
+#![recursion_limit = "256"]
+
+mod abilities;
+mod asset_tracking;
+mod attract;
+mod audio;
+mod autopilot;
+mod camera;
+mod combo;
+pub(crate) use audio::MusicAssets;
+pub(crate) mod consts;
+mod daily;
+#[cfg(feature = "dev")]
+mod dev_tools;
+mod gravity;
+pub(crate) mod input;
+#[cfg(debug_assertions)]
+mod leaks;
+mod locale;
+mod materials;
+mod menus;
+mod minimap;
+mod mode;
+mod obstacle;
+mod particles;
+pub(crate) use obstacle::DeathReason;
+mod player;
+pub(crate) use player::PlayerAssets;
+mod rng;
+mod save;
+mod score;
+mod screens;
+pub mod simulation;
+mod sun;
+mod supernova;
+mod telemetry;
+#[cfg(test)]
+mod tests;
+mod theme;
+mod threats;
+mod trail;
+mod tutorial;
+mod upgrades;
+pub(crate) mod utils;
+
+use avian2d::prelude::*;
+use bevy::{
+    asset::AssetMetaCheck,
+    core_pipeline::{
+        bloom::Bloom,
+        tonemapping::{DebandDither, Tonemapping},
+    },
+    log::{Level, LogPlugin},
+    prelude::*,
+    render::{RenderPlugin, settings::WgpuSettings},
+    window::ExitCondition,
+    winit::WinitPlugin,
+};
+use bevy_enoki::{
+    EnokiPlugin, Particle2dEffect,
+    prelude::{ParticleEffectLoader, SpriteParticle2dMaterial},
+};
+use bevy_seedling::{prelude::*, profiling::ProfilingBackend};
+
+pub struct AppPlugin;
+
+impl Plugin for AppPlugin {
+    fn build(&self, app: &mut App) {
+        // Add Bevy plugins.
+        app.add_plugins(
+            DefaultPlugins.set(asset_plugin()).set(WindowPlugin {
+                primary_window: Window {
+                    title: "Nova Looper".to_string(),
+                    fit_canvas_to_parent: true,
+                    ..default()
+                }
+                .into(),
+                ..default()
+            }),
+        );
+
+        // Third-party libs
+        app.add_plugins(PhysicsPlugins::default());
+        app.add_plugins(EnokiPlugin);
+        app.add_plugins(SeedlingPlugin::default());
+
+        add_game_plugins(app);
+    }
+}
+
+/// The game without a renderer or audio device, driven by calling
+/// [`App::update`]. Used for simulations and tests, so the save data is only
+/// kept in memory. The window is never opened, but keeps its default size for
+/// the systems that lay things out to fit it.
+pub struct HeadlessPlugin;
+
+impl Plugin for HeadlessPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(
+            DefaultPlugins
+                .set(asset_plugin())
+                .set(WindowPlugin {
+                    exit_condition: ExitCondition::DontExit,
+                    ..default()
+                })
+                .set(RenderPlugin {
+                    render_creation: WgpuSettings {
+                        backends: None,
+                        ..default()
+                    }
+                    .into(),
+                    ..default()
+                })
+                .set(LogPlugin {
+                    level: Level::WARN,
+                    ..default()
+                })
+                .disable::<WinitPlugin>(),
+        );
+
+        // Third-party libs. The particles can't run without the renderer, so
+        // only their assets are added.
+        app.add_plugins(PhysicsPlugins::default());
+        app.init_asset::<Particle2dEffect>()
+            .init_asset::<SpriteParticle2dMaterial>()
+            .init_asset_loader::<ParticleEffectLoader>();
+        app.add_plugins(SeedlingPlugin::<ProfilingBackend>::new());
+
+        app.insert_resource(save::InMemorySave);
+        add_game_plugins(app);
+    }
+}
+
+fn asset_plugin() -> AssetPlugin {
+    AssetPlugin {
+        // Wasm builds will check for meta files (that don't exist) if this isn't set.
+        // This causes errors and even panics on web build on itch.
+        // See https://github.com/bevyengine/bevy_github_ci_template/issues/48.
+        meta_check: AssetMetaCheck::Never,
+        ..default()
+    }
+}
+
+fn add_game_plugins(app: &mut App) {
+    // Add other plugins. These are split into groups as `add_plugins` only
+    // accepts tuples of up to 15 elements.
+    app.add_plugins((
+        (
+            abilities::plugin,
+            asset_tracking::plugin,
+            attract::plugin,
+            audio::plugin,
+            autopilot::plugin,
+            camera::plugin,
+            combo::plugin,
+            daily::plugin,
+            #[cfg(feature = "dev")]
+            dev_tools::plugin,
+            gravity::plugin,
+            input::plugin,
+            #[cfg(debug_assertions)]
+            leaks::plugin,
+            locale::plugin,
+            materials::plugin,
+            menus::plugin,
+        ),
+        (
+            minimap::plugin,
+            mode::plugin,
+            obstacle::plugin,
+            particles::plugin,
+            player::plugin,
+            rng::plugin,
+            save::plugin,
+            score::plugin,
+            screens::plugin,
+            sun::plugin,
+            supernova::plugin,
+            telemetry::plugin,
+            theme::plugin,
+            threats::plugin,
+            trail::plugin,
+        ),
+        (tutorial::plugin, upgrades::plugin, utils::plugin),
+    ));
+
+    // #[cfg(debug_assertions)]
+    // app.add_plugins(PhysicsDebugPlugin::default());
+
+    // Order new `AppSystems` variants by adding them here:
+    app.configure_sets(
+        Update,
+        (
+            AppSystems::TickTimers,
+            AppSystems::RecordInput,
+            AppSystems::Update,
+        )
+            .chain(),
+    );
+
+    // Set up the `Pause` state.
+    app.init_state::<Pause>();
+    app.configure_sets(Update, PausableSystems.run_if(in_state(Pause(false))));
+
+    // Spawn the main camera.
+    app.add_systems(Startup, spawn_camera);
+}
+
+/// High-level groupings of systems for the app in the `Update` schedule.
+/// When adding a new variant, make sure to order it in the `configure_sets`
+/// call above.
+#[derive(SystemSet, Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord)]
+enum AppSystems {
+    /// Tick timers.
+    TickTimers,
+    /// Record player input.
+    RecordInput,
+    /// Do everything else (consider splitting this into further variants).
+    Update,
+}
+
+/// Whether or not the game is paused.
+#[derive(States, Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+#[states(scoped_entities)]
+struct Pause(pub bool);
+
+/// A system set for systems that shouldn't run while the game is paused.
+#[derive(SystemSet, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+struct PausableSystems;
+
+fn spawn_camera(mut commands: Commands) {
+    commands.spawn((
+        Name::new("Camera"),
+        Camera2d,
+        Camera {
+            hdr: true,
+            ..default()
+        },
+        Tonemapping::TonyMcMapface,
+        Bloom::default(),
+        DebandDither::Enabled,
+    ));
+}
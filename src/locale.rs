@@ -0,0 +1,218 @@
+//! Translations of the player-facing text, with one string file per language
+//! in `assets/locales`. The files are built into the binary so they can be
+//! checked against each other in the tests.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::save::SaveData;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<Language>();
+    app.register_type::<UiText>();
+
+    app.init_resource::<Locale>();
+
+    app.add_systems(Startup, replace_default_font);
+
+    app.add_systems(
+        PreUpdate,
+        select_language.run_if(resource_changed::<SaveData>),
+    );
+    app.add_systems(Update, localize_ui_text);
+}
+
+#[derive(Reflect, Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    #[default]
+    English,
+    Spanish,
+    French,
+    German,
+}
+
+impl Language {
+    /// The name of the language, written in that language so players can
+    /// always find their own
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::Spanish => "Español",
+            Language::French => "Français",
+            Language::German => "Deutsch",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            Language::English => Language::Spanish,
+            Language::Spanish => Language::French,
+            Language::French => Language::German,
+            Language::German => Language::English,
+        }
+    }
+
+    pub fn previous(&self) -> Self {
+        match self {
+            Language::English => Language::German,
+            Language::Spanish => Language::English,
+            Language::French => Language::Spanish,
+            Language::German => Language::French,
+        }
+    }
+
+    fn strings_file(&self) -> &'static str {
+        match self {
+            Language::English => include_str!("../assets/locales/en.ron"),
+            Language::Spanish => include_str!("../assets/locales/es.ron"),
+            Language::French => include_str!("../assets/locales/fr.ron"),
+            Language::German => include_str!("../assets/locales/de.ron"),
+        }
+    }
+
+    fn strings(&self) -> Result<HashMap<String, String>, ron::error::SpannedError> {
+        ron::from_str(self.strings_file())
+    }
+
+    /// The separator placed between each group of three digits
+    pub fn thousands_separator(&self) -> char {
+        match self {
+            Language::English => ',',
+            Language::Spanish | Language::German => '.',
+            Language::French => ' ',
+        }
+    }
+}
+
+/// The strings for the player's chosen language
+#[derive(Resource, Debug)]
+pub struct Locale {
+    language: Language,
+    strings: HashMap<String, String>,
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self::new(Language::default())
+    }
+}
+
+impl Locale {
+    pub fn new(language: Language) -> Self {
+        let strings = language
+            .strings()
+            .unwrap_or_else(|err| panic!("invalid strings for {language:?}: {err}"));
+        Self { language, strings }
+    }
+
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    /// The string for the key, or the key itself if it has no translation
+    pub fn get<'a>(&'a self, key: &'a str) -> &'a str {
+        match self.strings.get(key) {
+            Some(string) => string,
+            None => {
+                warn!("missing {:?} string for {key}", self.language);
+                key
+            }
+        }
+    }
+
+    /// The string for the key with each `{name}` replaced by its argument
+    pub fn format(&self, key: &str, args: &[(&str, &dyn std::fmt::Display)]) -> String {
+        let mut string = self.get(key).to_string();
+        for (name, value) in args {
+            string = string.replace(&format!("{{{name}}}"), &value.to_string());
+        }
+        string
+    }
+}
+
+/// The text shown by a widget, either a key for a string in the [`Locale`] or
+/// text that is shown as it is
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component)]
+pub enum UiText {
+    Key(&'static str),
+    Literal(String),
+}
+
+impl UiText {
+    /// The text to spawn with, before any key has been looked up
+    pub fn initial(&self) -> String {
+        match self {
+            UiText::Key(_) => String::new(),
+            UiText::Literal(text) => text.clone(),
+        }
+    }
+}
+
+impl From<String> for UiText {
+    fn from(text: String) -> Self {
+        UiText::Literal(text)
+    }
+}
+
+/// Bevy's built in font only covers ASCII, so swap in the full version of the
+/// same font to show the accented letters in the translations
+fn replace_default_font(mut fonts: ResMut<Assets<Font>>) {
+    let font = Font::try_from_bytes(include_bytes!("../assets/fonts/FiraMono-Medium.ttf").to_vec())
+        .expect("the bundled font is valid");
+    fonts.insert(AssetId::default(), font);
+}
+
+fn select_language(save: Res<SaveData>, mut locale: ResMut<Locale>) {
+    if locale.language != save.language {
+        *locale = Locale::new(save.language);
+    }
+}
+
+fn localize_ui_text(locale: Res<Locale>, mut texts: Query<(Ref<UiText>, &mut Text)>) {
+    for (ui_text, mut text) in &mut texts {
+        if !locale.is_changed() && !ui_text.is_added() {
+            continue;
+        }
+
+        if let UiText::Key(key) = *ui_text {
+            text.0 = locale.get(key).to_string();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::*;
+
+    #[test]
+    fn every_language_has_every_key() {
+        let keys = |language: Language| -> BTreeSet<String> {
+            language
+                .strings()
+                .unwrap_or_else(|err| panic!("invalid strings for {language:?}: {err}"))
+                .into_keys()
+                .collect()
+        };
+
+        let english = keys(Language::English);
+        for language in [Language::Spanish, Language::French, Language::German] {
+            let other = keys(language);
+            let missing: Vec<_> = english.difference(&other).collect();
+            let unused: Vec<_> = other.difference(&english).collect();
+
+            assert!(
+                missing.is_empty(),
+                "{language:?} is missing keys: {missing:?}"
+            );
+            assert!(
+                unused.is_empty(),
+                "{language:?} has keys that are not in English: {unused:?}"
+            );
+        }
+    }
+}
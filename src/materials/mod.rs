@@ -1,7 +1,9 @@
 use bevy::prelude::*;
 
 mod power_bar;
+#[allow(dead_code, reason = "bevy issue 19573")]
 mod starfield;
+#[allow(dead_code, reason = "bevy issue 19573")]
 mod sun;
 
 pub use power_bar::BarDataSource;
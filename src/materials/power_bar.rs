@@ -1,13 +1,12 @@
-use bevy::{
-    color::palettes::{css::WHITE, tailwind::EMERALD_400},
-    prelude::*,
-    render::render_resource::AsBindGroup,
-};
+use bevy::{prelude::*, render::render_resource::AsBindGroup};
 
 use crate::{
     Pause,
+    combo::SkimCombo,
+    locale::UiText,
     player::{PlayerPower, PlayerShield},
     screens::Screen,
+    theme::{layout, palette::Palette},
 };
 
 const SHADER_ASSET_PATH: &str = "shaders/power_bar.wgsl";
@@ -17,12 +16,14 @@ pub(super) fn plugin(app: &mut App) {
     app.register_type::<PowerBarMarker>();
     app.register_type::<ShieldBarParentMarker>();
     app.register_type::<ShieldBarMarker>();
+    app.register_type::<ComboBarParentMarker>();
+    app.register_type::<ComboBarMarker>();
 
     app.add_plugins(UiMaterialPlugin::<UiProgressBarMaterial>::default());
 
     app.add_systems(
         OnEnter(Screen::Gameplay),
-        (spawn_power_bar, spawn_shield_bar),
+        (spawn_power_bar, spawn_shield_bar, spawn_combo_bar),
     );
 
     app.add_systems(
@@ -30,9 +31,14 @@ pub(super) fn plugin(app: &mut App) {
         (
             update_bar::<PowerBarMarker, PlayerPower>,
             update_bar::<ShieldBarMarker, PlayerShield>,
+            update_bar::<ComboBarMarker, SkimCombo>,
         )
             .run_if(in_state(Screen::Gameplay).and(in_state(Pause(false)))),
     );
+    app.add_systems(
+        Update,
+        apply_bar_palette.run_if(in_state(Screen::Gameplay).and(resource_changed::<Palette>)),
+    );
 }
 
 #[derive(AsBindGroup, Asset, TypePath, Debug, Clone)]
@@ -67,9 +73,18 @@ pub struct ShieldBarParentMarker;
 #[reflect(Component)]
 pub struct ShieldBarMarker;
 
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct ComboBarParentMarker;
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct ComboBarMarker;
+
 fn spawn_power_bar(
     mut commands: Commands,
     mut ui_materials: ResMut<Assets<UiProgressBarMaterial>>,
+    palette: Res<Palette>,
     power_bars: Query<Entity, With<PowerBarParentMarker>>,
 ) {
     for entity in &power_bars {
@@ -88,20 +103,18 @@ fn spawn_power_bar(
         PowerBarParentMarker,
         StateScoped(Screen::Gameplay),
         children![
-            Text::new("POWER "),
+            (
+                Text::default(),
+                UiText::Key("hud.power"),
+                layout::font_size(20.0, 14.0),
+            ),
             (
                 PowerBarMarker,
-                Node {
-                    width: Val::Px(250.0 - 12.0),
-                    height: Val::Px(30.0 - 12.0),
-                    border: UiRect::all(Val::Px(3.0)),
-                    padding: UiRect::all(Val::Px(3.0)),
-                    ..default()
-                },
+                bar_node(),
                 MaterialNode(ui_materials.add(UiProgressBarMaterial {
-                    color: EMERALD_400.to_vec4(),
+                    color: palette.power_bar.to_srgba().to_vec4(),
                     slider: Vec4::splat(0.4),
-                    border_color: LinearRgba::WHITE.to_vec4(),
+                    border_color: palette.bar_border.to_srgba().to_vec4(),
                 })),
                 BorderRadius::all(Val::Px(3.0)),
             )
@@ -112,6 +125,7 @@ fn spawn_power_bar(
 fn spawn_shield_bar(
     mut commands: Commands,
     mut ui_materials: ResMut<Assets<UiProgressBarMaterial>>,
+    palette: Res<Palette>,
     heat_bars: Query<Entity, With<ShieldBarParentMarker>>,
 ) {
     for entity in &heat_bars {
@@ -130,20 +144,59 @@ fn spawn_shield_bar(
         PowerBarParentMarker,
         StateScoped(Screen::Gameplay),
         children![
-            Text::new("SHIELD "),
+            (
+                Text::default(),
+                UiText::Key("hud.shield"),
+                layout::font_size(20.0, 14.0),
+            ),
             (
                 ShieldBarMarker,
-                Node {
-                    width: Val::Px(250.0 - 12.0),
-                    height: Val::Px(30.0 - 12.0),
-                    border: UiRect::all(Val::Px(3.0)),
-                    padding: UiRect::all(Val::Px(3.0)),
-                    ..default()
-                },
+                bar_node(),
                 MaterialNode(ui_materials.add(UiProgressBarMaterial {
-                    color: WHITE.to_vec4(),
+                    color: palette.shield_bar.to_srgba().to_vec4(),
                     slider: Vec4::splat(0.4),
-                    border_color: LinearRgba::WHITE.to_vec4(),
+                    border_color: palette.bar_border.to_srgba().to_vec4(),
+                })),
+                BorderRadius::all(Val::Px(3.0)),
+            )
+        ],
+    ));
+}
+
+fn spawn_combo_bar(
+    mut commands: Commands,
+    mut ui_materials: ResMut<Assets<UiProgressBarMaterial>>,
+    palette: Res<Palette>,
+    combo_bars: Query<Entity, With<ComboBarParentMarker>>,
+) {
+    for entity in &combo_bars {
+        commands.entity(entity).despawn();
+    }
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(10.0),
+            right: Val::Px(12.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..Default::default()
+        },
+        ComboBarParentMarker,
+        StateScoped(Screen::Gameplay),
+        children![
+            (
+                Text::default(),
+                UiText::Key("hud.combo"),
+                layout::font_size(20.0, 14.0),
+            ),
+            (
+                ComboBarMarker,
+                bar_node(),
+                MaterialNode(ui_materials.add(UiProgressBarMaterial {
+                    color: palette.combo_bar.to_srgba().to_vec4(),
+                    slider: Vec4::splat(0.0),
+                    border_color: palette.bar_border.to_srgba().to_vec4(),
                 })),
                 BorderRadius::all(Val::Px(3.0)),
             )
@@ -151,6 +204,18 @@ fn spawn_shield_bar(
     ));
 }
 
+/// The bar itself, shortened to fit narrow screens
+fn bar_node() -> impl Bundle {
+    let node = |width: f32| Node {
+        width: Val::Px(width - 12.0),
+        height: Val::Px(30.0 - 12.0),
+        border: UiRect::all(Val::Px(3.0)),
+        padding: UiRect::all(Val::Px(3.0)),
+        ..default()
+    };
+    layout::responsive(node(250.0), node(100.0))
+}
+
 pub trait BarDataSource {
     fn current_frac(&self) -> f32;
 }
@@ -167,3 +232,29 @@ fn update_bar<M, S>(
         material.slider.x = source.current_frac();
     }
 }
+
+fn apply_bar_palette(
+    palette: Res<Palette>,
+    mut materials: ResMut<Assets<UiProgressBarMaterial>>,
+    bars: Query<(
+        &MaterialNode<UiProgressBarMaterial>,
+        Has<PowerBarMarker>,
+        Has<ShieldBarMarker>,
+    )>,
+) {
+    for (bar, is_power, is_shield) in &bars {
+        let Some(material) = materials.get_mut(bar) else {
+            continue;
+        };
+
+        let color = if is_power {
+            palette.power_bar
+        } else if is_shield {
+            palette.shield_bar
+        } else {
+            palette.combo_bar
+        };
+        material.color = color.to_srgba().to_vec4();
+        material.border_color = palette.bar_border.to_srgba().to_vec4();
+    }
+}
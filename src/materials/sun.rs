@@ -4,10 +4,15 @@ use bevy::{
     sprite::{AlphaMode2d, Material2d, Material2dPlugin},
 };
 
-use crate::consts::{INNER_SUN_COLOUR, SUN_COLOUR};
+use crate::{
+    consts::{INNER_SUN_COLOUR, SUN_COLOUR},
+    theme::palette::Palette,
+};
 
 pub(super) fn plugin(app: &mut App) {
     app.add_plugins(Material2dPlugin::<SunMaterial>::default());
+
+    app.add_systems(Update, apply_sun_palette);
 }
 
 #[derive(Asset, TypePath, AsBindGroup, ShaderType, Debug, Clone)]
@@ -20,6 +25,13 @@ pub struct SunMaterial {
     blur_start: f32,
 }
 
+impl SunMaterial {
+    pub fn set_colors(&mut self, inner: Color, outer: Color) {
+        self.inner_color = inner.to_srgba().to_vec4();
+        self.color = outer.to_srgba().to_vec4();
+    }
+}
+
 impl<'a> From<&'a SunMaterial> for SunMaterial {
     fn from(material: &'a SunMaterial) -> Self {
         material.clone()
@@ -45,3 +57,20 @@ impl Default for SunMaterial {
         }
     }
 }
+
+/// Recolours the suns when the palette changes
+fn apply_sun_palette(
+    palette: Res<Palette>,
+    mut materials: ResMut<Assets<SunMaterial>>,
+    suns: Query<Ref<MeshMaterial2d<SunMaterial>>>,
+) {
+    for handle in &suns {
+        if !palette.is_changed() && !handle.is_added() {
+            continue;
+        }
+
+        if let Some(material) = materials.get_mut(&handle.0) {
+            material.set_colors(palette.inner_sun, palette.sun);
+        }
+    }
+}
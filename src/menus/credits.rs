@@ -4,7 +4,7 @@ use bevy::{
     ecs::spawn::SpawnIter, input::common_conditions::input_just_pressed, prelude::*, ui::Val::*,
 };
 
-use crate::{asset_tracking::LoadResource, menus::Menu, theme::prelude::*};
+use crate::{asset_tracking::LoadResource, locale::UiText, menus::Menu, theme::prelude::*};
 
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(OnEnter(Menu::Credits), spawn_credits_menu);
@@ -24,11 +24,11 @@ fn spawn_credits_menu(mut commands: Commands) {
         GlobalZIndex(2),
         StateScoped(Menu::Credits),
         children![
-            widget::header("Created by"),
+            widget::header(UiText::Key("credits.created_by")),
             created_by(),
-            widget::header("Assets"),
+            widget::header(UiText::Key("credits.assets")),
             assets(),
-            widget::button("Back", go_back_on_click),
+            widget::button(UiText::Key("common.back"), go_back_on_click),
         ],
     ));
 }
@@ -39,37 +39,44 @@ fn created_by() -> impl Bundle {
 
 fn assets() -> impl Bundle {
     grid(vec![
-        ["Button SFX", "CC0 by Jaszunio15"],
-        ["Viktor NV-1 Synth", "https://nicroto.github.io/viktor/"],
         [
-            "Bevy logo",
-            "All rights reserved by the Bevy Foundation, permission granted for splash screen use when unmodified",
+            UiText::Key("credits.button_sfx"),
+            UiText::Key("credits.button_sfx.license"),
+        ],
+        [
+            UiText::Literal("Viktor NV-1 Synth".into()),
+            UiText::Literal("https://nicroto.github.io/viktor/".into()),
+        ],
+        [
+            UiText::Key("credits.font"),
+            UiText::Literal("Fira Mono, SIL Open Font License 1.1".into()),
+        ],
+        [
+            UiText::Key("credits.bevy_logo"),
+            UiText::Key("credits.bevy_logo.license"),
         ],
     ])
 }
 
-fn grid(content: Vec<[&'static str; 2]>) -> impl Bundle {
+fn grid(content: Vec<[UiText; 2]>) -> impl Bundle {
     (
         Name::new("Grid"),
-        Node {
+        layout::stacking_grid(Node {
             display: Display::Grid,
             row_gap: Px(10.0),
             column_gap: Px(30.0),
             grid_template_columns: RepeatedGridTrack::px(2, 400.0),
             ..default()
-        },
+        }),
         Children::spawn(SpawnIter(content.into_iter().flatten().enumerate().map(
             |(i, text)| {
                 (
                     widget::label(text),
-                    Node {
-                        justify_self: if i.is_multiple_of(2) {
-                            JustifySelf::End
-                        } else {
-                            JustifySelf::Start
-                        },
-                        ..default()
-                    },
+                    layout::grid_cell(if i.is_multiple_of(2) {
+                        JustifySelf::End
+                    } else {
+                        JustifySelf::Start
+                    }),
                 )
             },
         ))),
@@ -0,0 +1,115 @@
+//! The hangar, where the player picks the hull they fly.
+
+use bevy::{input::common_conditions::input_just_pressed, prelude::*, ui::Val::*};
+
+use crate::{
+    locale::{Locale, UiText},
+    menus::Menu,
+    player::Hull,
+    save::SaveData,
+    theme::{layout, widget},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Menu::Hangar), spawn_hangar_menu);
+    app.add_systems(
+        Update,
+        go_back.run_if(in_state(Menu::Hangar).and(input_just_pressed(KeyCode::Escape))),
+    );
+
+    app.register_type::<SelectedHullLabel>();
+    app.add_systems(
+        Update,
+        update_selected_hull_labels.run_if(in_state(Menu::Hangar)),
+    );
+}
+
+fn spawn_hangar_menu(mut commands: Commands) {
+    commands.spawn((
+        widget::ui_root("Hangar Menu"),
+        GlobalZIndex(2),
+        StateScoped(Menu::Hangar),
+        children![
+            widget::header(UiText::Key("hangar.header")),
+            hulls_grid(),
+            widget::button(UiText::Key("common.back"), go_back_on_click),
+        ],
+    ));
+}
+
+fn hulls_grid() -> impl Bundle {
+    (
+        Name::new("Hulls Grid"),
+        layout::stacking_grid(Node {
+            display: Display::Grid,
+            row_gap: Px(10.0),
+            column_gap: Px(30.0),
+            grid_template_columns: vec![
+                GridTrack::auto(),
+                GridTrack::px(400.0),
+                GridTrack::px(120.0),
+            ],
+            align_items: AlignItems::Center,
+            ..default()
+        }),
+        children![
+            widget::menu_button(
+                UiText::Key(Hull::Standard.name_key()),
+                select_hull(Hull::Standard)
+            ),
+            widget::label(UiText::Key(Hull::Standard.description_key())),
+            (
+                widget::label(String::new()),
+                SelectedHullLabel(Hull::Standard)
+            ),
+            widget::menu_button(
+                UiText::Key(Hull::Scout.name_key()),
+                select_hull(Hull::Scout)
+            ),
+            widget::label(UiText::Key(Hull::Scout.description_key())),
+            (widget::label(String::new()), SelectedHullLabel(Hull::Scout)),
+            widget::menu_button(
+                UiText::Key(Hull::Hauler.name_key()),
+                select_hull(Hull::Hauler)
+            ),
+            widget::label(UiText::Key(Hull::Hauler.description_key())),
+            (
+                widget::label(String::new()),
+                SelectedHullLabel(Hull::Hauler)
+            ),
+        ],
+    )
+}
+
+fn select_hull(hull: Hull) -> impl Fn(Trigger<Pointer<Click>>, ResMut<SaveData>) {
+    move |_, mut save| {
+        save.hull = hull;
+    }
+}
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct SelectedHullLabel(Hull);
+
+fn update_selected_hull_labels(
+    save: Res<SaveData>,
+    locale: Res<Locale>,
+    mut labels: Query<(&mut Text, &SelectedHullLabel)>,
+) {
+    for (mut text, SelectedHullLabel(hull)) in &mut labels {
+        text.0 = if save.hull == *hull {
+            locale.get("hangar.selected")
+        } else {
+            ""
+        }
+        .into();
+    }
+}
+
+fn go_back_on_click(_: Trigger<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Main);
+}
+
+fn go_back(mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Main);
+}
@@ -1,20 +1,30 @@
 //! The game's menus and transitions between them.
 
 mod credits;
+mod hangar;
 mod main;
+mod modes;
 mod pause;
 mod settings;
+mod shop;
 
 use bevy::prelude::*;
 
+use crate::{daily::DailyChallenge, mode::GameMode, screens::Screen};
+
 pub(super) fn plugin(app: &mut App) {
     app.init_state::<Menu>();
 
+    app.add_systems(OnEnter(Screen::Loading), (apply_pending_run, close_menu));
+
     app.add_plugins((
         credits::plugin,
+        hangar::plugin,
         main::plugin,
+        modes::plugin,
         settings::plugin,
         pause::plugin,
+        shop::plugin,
     ));
 }
 
@@ -25,6 +35,48 @@ pub enum Menu {
     None,
     Main,
     Credits,
+    Hangar,
+    Modes,
     Settings,
+    Shop,
     Pause,
 }
+
+/// Starts a new run, going via the loading screen, which waits for any assets
+/// still loading. The run's rules only take effect once the attract run behind
+/// the menu has ended, so it isn't rewarded or recorded as the new run.
+#[derive(Debug, Clone, Copy)]
+pub struct StartRun {
+    pub mode: GameMode,
+    pub daily: Option<DailyChallenge>,
+}
+
+impl Command for StartRun {
+    fn apply(self, world: &mut World) {
+        world.insert_resource(PendingRun(self));
+        world
+            .resource_mut::<NextState<Screen>>()
+            .set(Screen::Loading);
+    }
+}
+
+/// The run to start on entering the loading screen
+#[derive(Resource, Debug)]
+struct PendingRun(StartRun);
+
+fn apply_pending_run(mut commands: Commands, pending: Option<Res<PendingRun>>) {
+    let Some(pending) = pending else {
+        return;
+    };
+
+    commands.insert_resource(pending.0.mode);
+    match pending.0.daily {
+        Some(daily) => commands.insert_resource(daily),
+        None => commands.remove_resource::<DailyChallenge>(),
+    }
+    commands.remove_resource::<PendingRun>();
+}
+
+fn close_menu(mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::None);
+}
@@ -0,0 +1,83 @@
+//! The game mode selection menu, shown before starting a run.
+
+use bevy::{input::common_conditions::input_just_pressed, prelude::*, ui::Val::*};
+
+use crate::{
+    locale::UiText,
+    menus::{Menu, StartRun},
+    mode::GameMode,
+    theme::{layout, widget},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Menu::Modes), spawn_modes_menu);
+    app.add_systems(
+        Update,
+        go_back.run_if(in_state(Menu::Modes).and(input_just_pressed(KeyCode::Escape))),
+    );
+}
+
+fn spawn_modes_menu(mut commands: Commands) {
+    commands.spawn((
+        widget::ui_root("Modes Menu"),
+        GlobalZIndex(2),
+        StateScoped(Menu::Modes),
+        children![
+            widget::header(UiText::Key("modes.header")),
+            modes_grid(),
+            widget::button(UiText::Key("common.back"), go_back_on_click),
+        ],
+    ));
+}
+
+fn modes_grid() -> impl Bundle {
+    (
+        Name::new("Modes Grid"),
+        layout::stacking_grid(Node {
+            display: Display::Grid,
+            row_gap: Px(10.0),
+            column_gap: Px(30.0),
+            grid_template_columns: vec![GridTrack::auto(), GridTrack::px(400.0)],
+            align_items: AlignItems::Center,
+            ..default()
+        }),
+        children![
+            widget::menu_button(UiText::Key("mode.endless"), start_endless),
+            widget::label(UiText::Key("mode.endless.description")),
+            widget::menu_button(UiText::Key("mode.time_attack"), start_time_attack),
+            widget::label(UiText::Key("mode.time_attack.description")),
+            widget::menu_button(UiText::Key("mode.zen"), start_zen),
+            widget::label(UiText::Key("mode.zen.description")),
+            widget::menu_button(UiText::Key("mode.tutorial"), start_tutorial),
+            widget::label(UiText::Key("mode.tutorial.description")),
+        ],
+    )
+}
+
+fn start_endless(_: Trigger<Pointer<Click>>, commands: Commands) {
+    start_mode(GameMode::Endless, commands);
+}
+
+fn start_time_attack(_: Trigger<Pointer<Click>>, commands: Commands) {
+    start_mode(GameMode::TimeAttack, commands);
+}
+
+fn start_zen(_: Trigger<Pointer<Click>>, commands: Commands) {
+    start_mode(GameMode::Zen, commands);
+}
+
+fn start_tutorial(_: Trigger<Pointer<Click>>, commands: Commands) {
+    start_mode(GameMode::Tutorial, commands);
+}
+
+fn start_mode(mode: GameMode, mut commands: Commands) {
+    commands.queue(StartRun { mode, daily: None });
+}
+
+fn go_back_on_click(_: Trigger<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Main);
+}
+
+fn go_back(mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Main);
+}
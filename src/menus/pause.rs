@@ -2,7 +2,7 @@
 
 use bevy::{input::common_conditions::input_just_pressed, prelude::*};
 
-use crate::{menus::Menu, screens::Screen, theme::widget};
+use crate::{locale::UiText, menus::Menu, screens::Screen, theme::widget};
 
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(OnEnter(Menu::Pause), spawn_pause_menu);
@@ -18,10 +18,10 @@ fn spawn_pause_menu(mut commands: Commands) {
         GlobalZIndex(2),
         StateScoped(Menu::Pause),
         children![
-            widget::header("Game paused"),
-            widget::button("Continue", close_menu),
-            widget::button("Settings", open_settings_menu),
-            widget::button("Quit to title", quit_to_title),
+            widget::header(UiText::Key("pause.header")),
+            widget::button(UiText::Key("pause.continue"), close_menu),
+            widget::button(UiText::Key("menu.settings"), open_settings_menu),
+            widget::button(UiText::Key("pause.quit"), quit_to_title),
         ],
     ));
 }
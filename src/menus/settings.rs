@@ -2,9 +2,19 @@
 //!
 //! Additional settings and accessibility options should go here.
 
-use bevy::{audio::Volume, input::common_conditions::input_just_pressed, prelude::*, ui::Val::*};
+use bevy::{
+    audio::Volume, ecs::spawn::SpawnableList, input::common_conditions::input_just_pressed,
+    prelude::*, ui::Val::*,
+};
 
-use crate::{menus::Menu, screens::Screen, theme::prelude::*};
+use crate::{
+    locale::{Locale, UiText},
+    menus::Menu,
+    mode::GameMode,
+    save::SaveData,
+    screens::Screen,
+    theme::prelude::*,
+};
 
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(OnEnter(Menu::Settings), spawn_settings_menu);
@@ -18,6 +28,36 @@ pub(super) fn plugin(app: &mut App) {
         Update,
         update_global_volume_label.run_if(in_state(Menu::Settings)),
     );
+
+    app.register_type::<SettingToggleLabel>();
+    app.add_systems(
+        Update,
+        update_setting_toggle_labels.run_if(in_state(Menu::Settings)),
+    );
+
+    app.register_type::<UiScaleLabel>();
+    app.add_systems(
+        Update,
+        update_ui_scale_label.run_if(in_state(Menu::Settings)),
+    );
+
+    app.register_type::<LanguageLabel>();
+    app.add_systems(
+        Update,
+        update_language_label.run_if(in_state(Menu::Settings)),
+    );
+
+    app.register_type::<PaletteThemeLabel>();
+    app.add_systems(
+        Update,
+        update_palette_theme_label.run_if(in_state(Menu::Settings)),
+    );
+
+    app.register_type::<ParticleQualityLabel>();
+    app.add_systems(
+        Update,
+        update_particle_quality_label.run_if(in_state(Menu::Settings)),
+    );
 }
 
 fn spawn_settings_menu(mut commands: Commands) {
@@ -26,9 +66,9 @@ fn spawn_settings_menu(mut commands: Commands) {
         GlobalZIndex(2),
         StateScoped(Menu::Settings),
         children![
-            widget::header("Settings"),
+            widget::header(UiText::Key("settings.header")),
             settings_grid(),
-            widget::button("Back", go_back_on_click),
+            widget::button(UiText::Key("common.back"), go_back_on_click),
         ],
     ));
 }
@@ -36,35 +76,57 @@ fn spawn_settings_menu(mut commands: Commands) {
 fn settings_grid() -> impl Bundle {
     (
         Name::new("Settings Grid"),
-        Node {
+        layout::stacking_grid(Node {
             display: Display::Grid,
             row_gap: Px(10.0),
             column_gap: Px(30.0),
             grid_template_columns: RepeatedGridTrack::px(2, 400.0),
             ..default()
-        },
-        children![
+        }),
+        Children::spawn((
+            Spawn(setting_label("settings.volume")),
+            Spawn(global_volume_widget()),
+            Spawn(setting_label("settings.ui_scale")),
+            Spawn(ui_scale_widget()),
+            Spawn(setting_label("settings.language")),
+            Spawn(language_widget()),
+            Spawn(setting_label("settings.colours")),
+            Spawn(palette_theme_widget()),
+            Spawn(setting_label("settings.particles")),
+            Spawn(particle_quality_widget()),
             (
-                widget::label("Master Volume"),
-                Node {
-                    justify_self: JustifySelf::End,
-                    ..default()
-                }
+                setting_toggle_row(SettingToggle::ReduceFlashing),
+                setting_toggle_row(SettingToggle::Zoom),
+                setting_toggle_row(SettingToggle::Rotate),
+                setting_toggle_row(SettingToggle::Shake),
+                setting_toggle_row(SettingToggle::LookAhead),
+                setting_toggle_row(SettingToggle::DodgeAssist),
             ),
-            global_volume_widget(),
-        ],
+        )),
+    )
+}
+
+/// The label and widget for a toggle, as two cells of the settings grid
+fn setting_toggle_row(toggle: SettingToggle) -> impl SpawnableList<ChildOf> {
+    (
+        Spawn(setting_label(toggle.name_key())),
+        Spawn(setting_toggle_widget(toggle)),
+    )
+}
+
+fn setting_label(key: &'static str) -> impl Bundle {
+    (
+        widget::label(UiText::Key(key)),
+        layout::grid_cell(JustifySelf::End),
     )
 }
 
 fn global_volume_widget() -> impl Bundle {
     (
         Name::new("Global Volume Widget"),
-        Node {
-            justify_self: JustifySelf::Start,
-            ..default()
-        },
+        layout::grid_cell(JustifySelf::Start),
         children![
-            widget::button_small("-", lower_global_volume),
+            widget::button_small(UiText::Literal("-".into()), lower_global_volume),
             (
                 Name::new("Current Volume"),
                 Node {
@@ -72,9 +134,9 @@ fn global_volume_widget() -> impl Bundle {
                     justify_content: JustifyContent::Center,
                     ..default()
                 },
-                children![(widget::label(""), GlobalVolumeLabel)],
+                children![(widget::label(String::new()), GlobalVolumeLabel)],
             ),
-            widget::button_small("+", raise_global_volume),
+            widget::button_small(UiText::Literal("+".into()), raise_global_volume),
         ],
     )
 }
@@ -104,22 +166,281 @@ fn update_global_volume_label(
     label.0 = format!("{percent:3.0}%");
 }
 
+fn ui_scale_widget() -> impl Bundle {
+    (
+        Name::new("UI Scale Widget"),
+        layout::grid_cell(JustifySelf::Start),
+        children![
+            widget::button_small(
+                UiText::Literal("-".into()),
+                |_: Trigger<Pointer<Click>>, mut save: ResMut<SaveData>| {
+                    save.ui_scale = save.ui_scale.smaller();
+                }
+            ),
+            (
+                Name::new("Current UI Scale"),
+                Node {
+                    padding: UiRect::horizontal(Px(10.0)),
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                children![(widget::label(String::new()), UiScaleLabel)],
+            ),
+            widget::button_small(
+                UiText::Literal("+".into()),
+                |_: Trigger<Pointer<Click>>, mut save: ResMut<SaveData>| {
+                    save.ui_scale = save.ui_scale.larger();
+                }
+            ),
+        ],
+    )
+}
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct UiScaleLabel;
+
+fn update_ui_scale_label(save: Res<SaveData>, mut label: Single<&mut Text, With<UiScaleLabel>>) {
+    label.0 = format!("{:3}%", save.ui_scale.0);
+}
+
+fn language_widget() -> impl Bundle {
+    (
+        Name::new("Language Widget"),
+        layout::grid_cell(JustifySelf::Start),
+        children![
+            widget::button_small(
+                UiText::Literal("<".into()),
+                |_: Trigger<Pointer<Click>>, mut save: ResMut<SaveData>| {
+                    save.language = save.language.previous();
+                }
+            ),
+            (
+                Name::new("Current Language"),
+                Node {
+                    width: Px(200.0),
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                children![(widget::label(String::new()), LanguageLabel)],
+            ),
+            widget::button_small(
+                UiText::Literal(">".into()),
+                |_: Trigger<Pointer<Click>>, mut save: ResMut<SaveData>| {
+                    save.language = save.language.next();
+                }
+            ),
+        ],
+    )
+}
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct LanguageLabel;
+
+fn update_language_label(save: Res<SaveData>, mut label: Single<&mut Text, With<LanguageLabel>>) {
+    label.0 = save.language.native_name().into();
+}
+
+fn palette_theme_widget() -> impl Bundle {
+    (
+        Name::new("Palette Theme Widget"),
+        layout::grid_cell(JustifySelf::Start),
+        children![
+            widget::button_small(
+                UiText::Literal("<".into()),
+                |_: Trigger<Pointer<Click>>, mut save: ResMut<SaveData>| {
+                    save.palette = save.palette.previous();
+                }
+            ),
+            (
+                Name::new("Current Palette"),
+                Node {
+                    width: Px(200.0),
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                children![(widget::label(String::new()), PaletteThemeLabel)],
+            ),
+            widget::button_small(
+                UiText::Literal(">".into()),
+                |_: Trigger<Pointer<Click>>, mut save: ResMut<SaveData>| {
+                    save.palette = save.palette.next();
+                }
+            ),
+        ],
+    )
+}
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct PaletteThemeLabel;
+
+fn update_palette_theme_label(
+    save: Res<SaveData>,
+    locale: Res<Locale>,
+    mut label: Single<&mut Text, With<PaletteThemeLabel>>,
+) {
+    label.0 = locale.get(save.palette.name_key()).into();
+}
+
+fn particle_quality_widget() -> impl Bundle {
+    (
+        Name::new("Particle Quality Widget"),
+        layout::grid_cell(JustifySelf::Start),
+        children![
+            widget::button_small(
+                UiText::Literal("<".into()),
+                |_: Trigger<Pointer<Click>>, mut save: ResMut<SaveData>| {
+                    save.particle_quality = save.particle_quality.previous();
+                }
+            ),
+            (
+                Name::new("Current Particle Quality"),
+                Node {
+                    width: Px(200.0),
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                children![(widget::label(String::new()), ParticleQualityLabel)],
+            ),
+            widget::button_small(
+                UiText::Literal(">".into()),
+                |_: Trigger<Pointer<Click>>, mut save: ResMut<SaveData>| {
+                    save.particle_quality = save.particle_quality.next();
+                }
+            ),
+        ],
+    )
+}
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct ParticleQualityLabel;
+
+fn update_particle_quality_label(
+    save: Res<SaveData>,
+    locale: Res<Locale>,
+    mut label: Single<&mut Text, With<ParticleQualityLabel>>,
+) {
+    label.0 = locale.get(save.particle_quality.name_key()).into();
+}
+
+/// The settings that can be turned on and off, mostly for motion and
+/// light-sensitive players
+#[derive(Reflect, Debug, Clone, Copy)]
+enum SettingToggle {
+    Zoom,
+    Rotate,
+    Shake,
+    LookAhead,
+    ReduceFlashing,
+    DodgeAssist,
+}
+
+impl SettingToggle {
+    /// The locale key for the setting's name
+    fn name_key(&self) -> &'static str {
+        match self {
+            SettingToggle::Zoom => "settings.camera_zoom",
+            SettingToggle::Rotate => "settings.camera_rotation",
+            SettingToggle::Shake => "settings.screen_shake",
+            SettingToggle::LookAhead => "settings.look_ahead",
+            SettingToggle::ReduceFlashing => "settings.reduce_flashing",
+            SettingToggle::DodgeAssist => "settings.dodge_assist",
+        }
+    }
+
+    fn value(self, save: &SaveData) -> bool {
+        match self {
+            SettingToggle::Zoom => save.camera.zoom,
+            SettingToggle::Rotate => save.camera.rotate,
+            SettingToggle::Shake => save.camera.shake,
+            SettingToggle::LookAhead => save.camera.look_ahead,
+            SettingToggle::ReduceFlashing => save.reduce_flashing,
+            SettingToggle::DodgeAssist => save.dodge_assist,
+        }
+    }
+
+    fn value_mut(self, save: &mut SaveData) -> &mut bool {
+        match self {
+            SettingToggle::Zoom => &mut save.camera.zoom,
+            SettingToggle::Rotate => &mut save.camera.rotate,
+            SettingToggle::Shake => &mut save.camera.shake,
+            SettingToggle::LookAhead => &mut save.camera.look_ahead,
+            SettingToggle::ReduceFlashing => &mut save.reduce_flashing,
+            SettingToggle::DodgeAssist => &mut save.dodge_assist,
+        }
+    }
+}
+
+fn setting_toggle_widget(toggle: SettingToggle) -> impl Bundle {
+    let flip = move |_: Trigger<Pointer<Click>>, mut save: ResMut<SaveData>| {
+        let value = toggle.value_mut(&mut save);
+        *value = !*value;
+    };
+
+    (
+        Name::new(toggle.name_key()),
+        layout::grid_cell(JustifySelf::Start),
+        children![
+            widget::button_small(UiText::Literal("<".into()), flip),
+            (
+                Name::new("Current Value"),
+                Node {
+                    width: Px(80.0),
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                children![(widget::label(String::new()), SettingToggleLabel(toggle))],
+            ),
+            widget::button_small(UiText::Literal(">".into()), flip),
+        ],
+    )
+}
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct SettingToggleLabel(SettingToggle);
+
+fn update_setting_toggle_labels(
+    save: Res<SaveData>,
+    locale: Res<Locale>,
+    mut labels: Query<(&mut Text, &SettingToggleLabel)>,
+) {
+    for (mut text, SettingToggleLabel(toggle)) in &mut labels {
+        let key = if toggle.value(&save) {
+            "common.on"
+        } else {
+            "common.off"
+        };
+        text.0 = locale.get(key).into();
+    }
+}
+
 fn go_back_on_click(
     _: Trigger<Pointer<Click>>,
     screen: Res<State<Screen>>,
+    mode: Res<GameMode>,
     mut next_menu: ResMut<NextState<Menu>>,
 ) {
-    next_menu.set(if screen.get() == &Screen::Title {
-        Menu::Main
-    } else {
-        Menu::Pause
-    });
+    next_menu.set(parent_menu(screen.get(), &mode));
+}
+
+fn go_back(
+    screen: Res<State<Screen>>,
+    mode: Res<GameMode>,
+    mut next_menu: ResMut<NextState<Menu>>,
+) {
+    next_menu.set(parent_menu(screen.get(), &mode));
 }
 
-fn go_back(screen: Res<State<Screen>>, mut next_menu: ResMut<NextState<Menu>>) {
-    next_menu.set(if screen.get() == &Screen::Title {
+/// The main menu on the title screen, including over an attract run, or the
+/// pause menu during a run
+fn parent_menu(screen: &Screen, mode: &GameMode) -> Menu {
+    if *screen == Screen::Title || *mode == GameMode::Attract {
         Menu::Main
     } else {
         Menu::Pause
-    });
+    }
 }
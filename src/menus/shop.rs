@@ -0,0 +1,153 @@
+//! The upgrade shop, where credits earned from runs are spent on permanent
+//! ship upgrades.
+
+use bevy::{input::common_conditions::input_just_pressed, prelude::*, ui::Val::*};
+
+use crate::{
+    locale::{Locale, UiText},
+    menus::Menu,
+    save::SaveData,
+    theme::{layout, widget},
+    upgrades::{MAX_UPGRADE_LEVEL, Upgrade},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Menu::Shop), spawn_shop_menu);
+    app.add_systems(
+        Update,
+        go_back.run_if(in_state(Menu::Shop).and(input_just_pressed(KeyCode::Escape))),
+    );
+
+    app.register_type::<CreditsLabel>();
+    app.register_type::<UpgradeLabel>();
+    app.add_systems(
+        Update,
+        (update_credits_label, update_upgrade_labels).run_if(in_state(Menu::Shop)),
+    );
+}
+
+fn spawn_shop_menu(mut commands: Commands) {
+    commands.spawn((
+        widget::ui_root("Shop Menu"),
+        GlobalZIndex(2),
+        StateScoped(Menu::Shop),
+        children![
+            widget::header(UiText::Key("shop.header")),
+            (widget::label(String::new()), CreditsLabel),
+            (
+                Name::new("Upgrade List"),
+                layout::responsive(
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Px(10.0),
+                        ..default()
+                    },
+                    Node {
+                        width: Percent(100.0),
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Px(10.0),
+                        ..default()
+                    },
+                ),
+                children![
+                    upgrade_row(Upgrade::MaxShield),
+                    upgrade_row(Upgrade::Plating),
+                    upgrade_row(Upgrade::Thrusters),
+                    upgrade_row(Upgrade::PowerCore),
+                ],
+            ),
+            widget::button(UiText::Key("common.back"), go_back_on_click),
+        ],
+    ));
+}
+
+fn upgrade_row(upgrade: Upgrade) -> impl Bundle {
+    (
+        Name::new(upgrade.name_key()),
+        layout::responsive(
+            Node {
+                display: Display::Grid,
+                column_gap: Px(30.0),
+                grid_template_columns: vec![
+                    GridTrack::px(250.0),
+                    GridTrack::px(250.0),
+                    GridTrack::auto(),
+                ],
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            Node {
+                display: Display::Grid,
+                column_gap: Px(10.0),
+                grid_template_columns: vec![
+                    GridTrack::flex(1.0),
+                    GridTrack::flex(1.0),
+                    GridTrack::auto(),
+                ],
+                align_items: AlignItems::Center,
+                ..default()
+            },
+        ),
+        children![
+            widget::label(UiText::Key(upgrade.name_key())),
+            (widget::label(String::new()), UpgradeLabel(upgrade)),
+            widget::button_small(
+                UiText::Literal("+".into()),
+                move |_: Trigger<Pointer<Click>>, mut save: ResMut<SaveData>| {
+                    // only a purchase changes the save, not a failed attempt
+                    if save.bypass_change_detection().buy_upgrade(upgrade) {
+                        save.set_changed();
+                    }
+                }
+            ),
+        ],
+    )
+}
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct CreditsLabel;
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct UpgradeLabel(Upgrade);
+
+fn update_credits_label(
+    save: Res<SaveData>,
+    locale: Res<Locale>,
+    mut label: Single<&mut Text, With<CreditsLabel>>,
+) {
+    label.0 = locale.format("shop.credits", &[("credits", &save.credits)]);
+}
+
+fn update_upgrade_labels(
+    save: Res<SaveData>,
+    locale: Res<Locale>,
+    mut labels: Query<(&mut Text, &UpgradeLabel)>,
+) {
+    for (mut text, UpgradeLabel(upgrade)) in &mut labels {
+        let level = save.upgrades.level(*upgrade);
+        text.0 = match upgrade.cost(level) {
+            Some(cost) => locale.format(
+                "shop.level",
+                &[
+                    ("level", &level),
+                    ("max", &MAX_UPGRADE_LEVEL),
+                    ("cost", &cost),
+                ],
+            ),
+            None => locale.format(
+                "shop.maxed",
+                &[("level", &level), ("max", &MAX_UPGRADE_LEVEL)],
+            ),
+        };
+    }
+}
+
+fn go_back_on_click(_: Trigger<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Main);
+}
+
+fn go_back(mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Main);
+}
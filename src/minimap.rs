@@ -0,0 +1,153 @@
+//! A radial minimap of the current orbit, so the player can see the obstacles
+//! coming round from the far side of the sun.
+
+use bevy::{
+    color::palettes::css::{MEDIUM_PURPLE, WHITE, YELLOW},
+    prelude::*,
+};
+
+use crate::{
+    consts::{MAIN_THEME_COLOR, MAX_PLAYER_RADIUS},
+    obstacle::{BlackHole, Obstacle},
+    player::Player,
+    screens::Screen,
+    sun::Sun,
+};
+
+/// The size of the whole minimap in pixels
+const MINIMAP_SIZE: f32 = 180.0;
+/// The radius the sun is drawn with in pixels, the orbit fills the rest
+const MINIMAP_SUN_RADIUS: f32 = 30.0;
+const DOT_SIZE: f32 = 6.0;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<Minimap>();
+    app.register_type::<MinimapDot>();
+    app.register_type::<OnMinimap>();
+
+    app.add_systems(OnEnter(Screen::Gameplay), spawn_minimap);
+    app.add_systems(
+        PostUpdate,
+        (spawn_minimap_dots, update_minimap_dots)
+            .chain()
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct Minimap;
+
+/// A dot on the minimap showing the position of the target entity
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct MinimapDot {
+    target: Entity,
+}
+
+/// Marks entities that already have a dot on the minimap
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct OnMinimap;
+
+fn spawn_minimap(mut commands: Commands) {
+    commands.spawn((
+        Name::new("Minimap"),
+        Minimap,
+        StateScoped(Screen::Gameplay),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(10.0),
+            left: Val::Px(10.0),
+            width: Val::Px(MINIMAP_SIZE),
+            height: Val::Px(MINIMAP_SIZE),
+            border: UiRect::all(Val::Px(1.0)),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.05)),
+        BorderColor(Color::srgba(1.0, 1.0, 1.0, 0.4)),
+        BorderRadius::MAX,
+        Pickable::IGNORE,
+        children![(
+            Name::new("Minimap Sun"),
+            Node {
+                width: Val::Px(2.0 * MINIMAP_SUN_RADIUS),
+                height: Val::Px(2.0 * MINIMAP_SUN_RADIUS),
+                ..default()
+            },
+            BackgroundColor(MAIN_THEME_COLOR),
+            BorderRadius::MAX,
+        )],
+    ));
+}
+
+fn spawn_minimap_dots(
+    mut commands: Commands,
+    minimap: Single<Entity, With<Minimap>>,
+    targets: Query<
+        (Entity, Has<Player>, Has<BlackHole>),
+        (
+            Or<(With<Player>, With<Obstacle>, With<BlackHole>)>,
+            Without<OnMinimap>,
+        ),
+    >,
+) {
+    for (target, is_player, is_black_hole) in &targets {
+        let (color, size) = if is_player {
+            (Color::from(WHITE), DOT_SIZE + 2.0)
+        } else if is_black_hole {
+            (MEDIUM_PURPLE.into(), DOT_SIZE + 2.0)
+        } else {
+            (YELLOW.into(), DOT_SIZE)
+        };
+
+        commands.entity(target).try_insert(OnMinimap);
+        commands.entity(*minimap).with_child((
+            MinimapDot { target },
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Px(size),
+                height: Val::Px(size),
+                ..default()
+            },
+            BackgroundColor(color),
+            BorderRadius::MAX,
+            // draw the player over everything else
+            ZIndex(is_player as i32),
+        ));
+    }
+}
+
+/// Moves each dot to its target's position in polar space, or removes it if
+/// the target has gone
+fn update_minimap_dots(
+    mut commands: Commands,
+    sun: Single<&Sun>,
+    targets: Query<&GlobalTransform>,
+    mut dots: Query<(Entity, &MinimapDot, &mut Node)>,
+) {
+    let orbit_size = MINIMAP_SIZE / 2.0 - MINIMAP_SUN_RADIUS;
+
+    for (entity, dot, mut node) in &mut dots {
+        let Ok(target) = targets.get(dot.target) else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+
+        let translation = target.translation().truncate();
+        let distance = (translation.length() - sun.radius).clamp(0.0, MAX_PLAYER_RADIUS);
+        let theta = translation.x.atan2(translation.y);
+
+        let radius = MINIMAP_SUN_RADIUS + orbit_size * distance / MAX_PLAYER_RADIUS;
+        let half_size = match node.width {
+            Val::Px(width) => width / 2.0,
+            _ => 0.0,
+        };
+
+        // ui coordinates have y pointing down
+        node.left = Val::Px(MINIMAP_SIZE / 2.0 + radius * theta.sin() - half_size);
+        node.top = Val::Px(MINIMAP_SIZE / 2.0 - radius * theta.cos() - half_size);
+    }
+}
@@ -0,0 +1,128 @@
+//! Game modes, which tweak the rules of the existing gameplay systems rather
+//! than replacing them.
+
+use bevy::{color::palettes::css::WHITE, prelude::*};
+use serde::Serialize;
+
+use crate::{DeathReason, screens::Screen, theme::layout};
+
+const TIME_ATTACK_DURATION: f32 = 180.0;
+const TIMES_UP: &str = "death.times_up";
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<GameMode>();
+    app.register_type::<TimeAttackTimer>();
+    app.register_type::<TimeAttackMarker>();
+
+    app.init_resource::<GameMode>();
+
+    app.add_systems(
+        OnEnter(Screen::Gameplay),
+        setup_time_attack.run_if(resource_equals(GameMode::TimeAttack)),
+    );
+    app.add_systems(OnExit(Screen::Gameplay), remove_time_attack);
+    app.add_systems(
+        Update,
+        (tick_time_attack, update_time_attack_text)
+            .chain()
+            .run_if(in_state(Screen::Gameplay).and(resource_exists::<TimeAttackTimer>)),
+    );
+}
+
+/// The rules for the current run, chosen from the main menu
+#[derive(Resource, Reflect, Serialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[reflect(Resource)]
+#[serde(rename_all = "snake_case")]
+pub enum GameMode {
+    /// Keep going until the shield runs out
+    #[default]
+    Endless,
+    /// A fixed length run, chasing the highest score at the buzzer
+    TimeAttack,
+    /// No obstacles and no shield damage, just skimming
+    Zen,
+    /// A scripted run that teaches the mechanics one step at a time
+    Tutorial,
+    /// The autopilot playing behind the main menu on the title screen
+    Attract,
+}
+
+impl GameMode {
+    pub fn has_obstacles(&self) -> bool {
+        !matches!(self, GameMode::Zen)
+    }
+
+    pub fn damages_shield(&self) -> bool {
+        !matches!(self, GameMode::Zen)
+    }
+
+    /// Whether the run's score is converted into credits for the shop
+    pub fn earns_credits(&self) -> bool {
+        matches!(self, GameMode::Endless | GameMode::TimeAttack)
+    }
+
+    /// Whether running out of shield or hitting a black hole ends the run
+    pub fn can_die(&self) -> bool {
+        !matches!(self, GameMode::Tutorial)
+    }
+}
+
+/// Run condition for systems that spawn obstacles or black holes
+pub fn has_obstacles(mode: Res<GameMode>) -> bool {
+    mode.has_obstacles()
+}
+
+#[derive(Resource, Reflect, Debug)]
+#[reflect(Resource)]
+pub struct TimeAttackTimer(pub Timer);
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct TimeAttackMarker;
+
+fn setup_time_attack(mut commands: Commands) {
+    commands.insert_resource(TimeAttackTimer(Timer::from_seconds(
+        TIME_ATTACK_DURATION,
+        TimerMode::Once,
+    )));
+
+    commands.spawn((
+        Text::new(""),
+        layout::font_size(32.0, 24.0),
+        TextColor(WHITE.into()),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(10.0),
+            left: Val::Px(10.0),
+            ..default()
+        },
+        TimeAttackMarker,
+        StateScoped(Screen::Gameplay),
+    ));
+}
+
+fn remove_time_attack(mut commands: Commands) {
+    commands.remove_resource::<TimeAttackTimer>();
+}
+
+fn tick_time_attack(
+    time: Res<Time>,
+    mut timer: ResMut<TimeAttackTimer>,
+    mut death_reason: ResMut<DeathReason>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    timer.0.tick(time.delta());
+
+    if timer.0.just_finished() {
+        death_reason.0 = TIMES_UP;
+        next_screen.set(Screen::GameOver);
+    }
+}
+
+fn update_time_attack_text(
+    timer: Res<TimeAttackTimer>,
+    mut text: Single<&mut Text, With<TimeAttackMarker>>,
+) {
+    let remaining = timer.0.remaining_secs().ceil() as u32;
+    text.0 = format!("{}:{:02}", remaining / 60, remaining % 60);
+}
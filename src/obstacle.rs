@@ -1,236 +1,335 @@
-use avian2d::prelude::*;
-use bevy::prelude::*;
-use bevy_enoki::{
-    NoAutoAabb, ParticleEffectHandle, ParticleSpawner,
-    prelude::{OneShot, ParticleEffectInstance, ParticleSpawnerState, Rval},
-};
-use bevy_seedling::sample::SamplePlayer;
-use rand::{Rng, thread_rng};
-
-/// nova obstacles
-mod nova;
-
-use crate::{
-    PlayerAssets,
-    consts::{MAX_PLAYER_RADIUS, OBSTACLE_COLOR, SHIELD_COST_ON_OBSTACLE_HIT},
-    obstacle::nova::BlackHole,
-    player::{ItemPosition, Player, PlayerPower, PlayerShield},
-    score::Score,
-    screens::Screen,
-    sun::Sun,
-    supernova::Nova,
-    utils::{self, DestroyAt},
-};
-
-const BURNED_UP: &str = "You burned up in the sun.";
-const BLACK_HOLE: &str = "You flew into a black hole.";
-
-pub(super) fn plugin(app: &mut App) {
-    app.register_type::<DeathReason>();
-    app.register_type::<Obstacle>();
-    app.register_type::<AsteroidDebris>();
-
-    app.init_resource::<DeathReason>();
-
-    app.add_plugins(nova::plugin);
-
-    app.add_systems(OnEnter(Screen::Gameplay), reset_death_reason);
-
-    app.add_systems(
-        Update,
-        (
-            (periodically_spawn_obstacles, collide_obstacles).run_if(in_state(Nova::Idle)),
-            collide_obstacles.run_if(in_state(Nova::During)),
-            // this doesn't seem to work :shrug
-            update_debris_gravity_direction,
-        ),
-    );
-}
-
-#[derive(Component, Reflect)]
-#[reflect(Component)]
-pub struct Obstacle;
-
-#[derive(Resource, Reflect)]
-#[reflect(Resource)]
-pub struct DeathReason(pub String);
-
-impl Default for DeathReason {
-    fn default() -> Self {
-        Self(BURNED_UP.into())
-    }
-}
-
-fn reset_death_reason(mut death_reason: ResMut<DeathReason>) {
-    death_reason.0 = BURNED_UP.into();
-}
-
-fn periodically_spawn_obstacles(
-    mut commands: Commands,
-    time: Res<Time>,
-    score: Res<Score>,
-    nova_state: Res<State<Nova>>,
-    mut timer: Local<f32>,
-    player: Single<&ItemPosition, With<Player>>,
-) {
-    if *timer > 0.0 {
-        *timer -= time.delta_secs();
-        return;
-    }
-
-    let mut rng = thread_rng();
-
-    *timer = rng.gen_range(0.1..0.4);
-    let num_obstacles = rng.gen_range(1..=3);
-    let radius = rng.gen_range(-75.0..(MAX_PLAYER_RADIUS * 0.5));
-
-    // account for the fact that the player speeds up over time. This should be
-    // sufficient for despawning on the other side of the world as the multiplier
-    // doesn't increase that quickly while the player is dodging.
-    let extra_speed = utils::get_player_speed_multipliers(score.multiplier, &player, **nova_state);
-    let speed = player.speed * (extra_speed.0 + extra_speed.1);
-
-    for _ in 0..num_obstacles {
-        let radius = radius + rng.gen_range(-60.0..20.0);
-        let theta = player.theta + std::f32::consts::PI + rng.gen_range(-0.05..=0.05);
-        commands.queue(SpawnObstacle {
-            theta,
-            radius,
-            speed: rng.gen_range(-30.0..-15.0),
-            // destroy after one revolution at the player's current speed
-            destroy_at: std::f32::consts::TAU / speed + time.elapsed_secs(),
-        });
-    }
-}
-
-#[derive(Debug, Clone, Copy)]
-struct SpawnObstacle {
-    theta: f32,
-    radius: f32,
-    destroy_at: f32,
-    speed: f32,
-}
-
-impl Command for SpawnObstacle {
-    fn apply(self, world: &mut World) {
-        let _ = world.run_system_cached_with(spawn_obstacle, self);
-    }
-}
-
-fn spawn_obstacle(
-    config: In<SpawnObstacle>,
-    mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<ColorMaterial>>,
-    sun: Single<&Sun>,
-) {
-    // info!("Spawning obstacle");
-    let mesh = meshes.add(Rectangle::new(1.0, 15.0));
-
-    let radius = config.radius + sun.radius;
-    let theta = config.theta;
-    let translation = Vec3::new(radius * theta.sin(), radius * theta.cos(), -1.0);
-
-    commands.spawn((
-        Obstacle,
-        Transform::from_translation(translation).with_rotation(Quat::from_axis_angle(
-            Vec3::Z,
-            translation.truncate().to_angle() + std::f32::consts::FRAC_PI_2,
-        )),
-        RigidBody::Dynamic,
-        Collider::rectangle(4.0, 14.0),
-        LinearVelocity(-translation.truncate().normalize() * config.speed),
-        Sensor,
-        StateScoped(Screen::Gameplay),
-        DestroyAt {
-            time: config.destroy_at,
-        },
-        ItemPosition {
-            radius,
-            theta,
-            speed: 0.0,
-            center: Vec2::ZERO,
-        },
-        Visibility::Visible,
-        Mesh2d(mesh),
-        MeshMaterial2d(materials.add(OBSTACLE_COLOR)),
-    ));
-}
-
-#[derive(Debug, Component, Reflect)]
-#[reflect(Component)]
-pub struct AsteroidDebris;
-
-fn collide_obstacles(
-    mut commands: Commands,
-    player_assets: Option<Res<PlayerAssets>>,
-    asset_server: Res<AssetServer>,
-    mut screen: ResMut<NextState<Screen>>,
-    mut death_reason: ResMut<DeathReason>,
-    colliders: Query<(Entity, &CollidingEntities)>,
-    obstacles: Query<&Transform, With<Obstacle>>,
-    warp_barriers: Query<(), With<BlackHole>>,
-    mut power: Single<(&mut PlayerPower, &mut PlayerShield)>,
-) {
-    for (_entity, colliding) in &colliders {
-        if colliding.is_empty() {
-            continue;
-        }
-
-        for collider in colliding.iter() {
-            if warp_barriers.get(*collider).is_ok() {
-                // uh oh we dead, can't go round hitting things in warp
-                screen.set(Screen::GameOver);
-                death_reason.0 = BLACK_HOLE.into();
-
-                if let Some(player_assets) = &player_assets {
-                    commands.spawn(SamplePlayer::new(player_assets.obstacle_hit.clone()));
-                }
-            }
-
-            if let Ok(tx) = obstacles.get(*collider) {
-                power.0.0 = (power.0.0 - 25.0).clamp(0.0, 100.0);
-                power.1.0 = (power.1.0 - SHIELD_COST_ON_OBSTACLE_HIT).clamp(0.0, 100.0);
-
-                // create a particle effect
-                let mut new_tx = tx.translation;
-                new_tx.z = 0.2;
-
-                let effect = asset_server.load("particles/asteroid_hit.ron");
-
-                commands.spawn((
-                    AsteroidDebris,
-                    StateScoped(Screen::Gameplay),
-                    Transform::from_translation(new_tx),
-                    ParticleSpawner::default(),
-                    ParticleEffectHandle(effect),
-                    ParticleSpawnerState::default(),
-                    OneShot::Despawn,
-                    NoAutoAabb,
-                ));
-
-                // destroy obstacle
-                commands.entity(*collider).despawn();
-
-                if let Some(player_assets) = &player_assets {
-                    commands.spawn(SamplePlayer::new(player_assets.obstacle_hit.clone()));
-                }
-            }
-        }
-    }
-}
-
-fn update_debris_gravity_direction(
-    mut commands: Commands,
-    mut particles: Query<(Entity, &Transform, &mut ParticleEffectInstance), With<AsteroidDebris>>,
-) {
-    for (entity, tx, mut maybe_effect) in &mut particles {
-        // info!("Setting gravity on particles");
-        let direction = -tx.translation;
-        if let Some(effect) = maybe_effect.0.as_mut() {
-            effect.gravity_direction = Some(Rval::new(direction.truncate(), 0.0));
-        }
-
-        commands.entity(entity).remove::<AsteroidDebris>();
-    }
-}
+use std::f32::consts::{PI, TAU};
+
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use bevy_seedling::sample::SamplePlayer;
+use rand::Rng;
+use serde::Serialize;
+
+/// near miss bonuses
+mod near_miss;
+/// nova obstacles
+mod nova;
+
+pub use nova::BlackHole;
+
+use crate::{
+    PlayerAssets,
+    abilities::Phase,
+    consts::{MAX_PLAYER_RADIUS, PLAYER_STARTING_SPEED},
+    daily::DailyChallenge,
+    mode::{GameMode, has_obstacles},
+    particles::{Effect, PlayParticles},
+    player::{ItemPosition, Player, PlayerPower, PlayerShield, ShipStats},
+    rng::RunRng,
+    score::Score,
+    screens::Screen,
+    sun::Sun,
+    supernova::Nova,
+    theme::palette::Palette,
+    tutorial::tutorial_allows_obstacles,
+    utils::{self, DestroyAt},
+};
+
+const BURNED_UP: &str = "death.burned_up";
+const BLACK_HOLE: &str = "death.black_hole";
+
+/// Debris falls much faster than the flares, at 150 pixels per second, so it
+/// reaches the sun before the particles fade
+const DEBRIS_GRAVITY_SCALE: f32 = 75.0;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<DeathReason>();
+    app.register_type::<Obstacle>();
+    app.register_type::<AsteroidDebris>();
+    app.register_type::<ObstacleSchedule>();
+
+    app.init_resource::<DeathReason>();
+    app.init_resource::<ObstacleSchedule>();
+    app.add_event::<PlayerCollision>();
+    app.add_event::<PlayerDied>();
+
+    app.add_plugins((nova::plugin, near_miss::plugin));
+
+    app.add_systems(
+        OnEnter(Screen::Gameplay),
+        (reset_death_reason, reset_obstacle_schedule),
+    );
+    app.add_systems(OnEnter(Screen::GameOver), announce_death);
+
+    app.add_systems(
+        Update,
+        (
+            (
+                periodically_spawn_obstacles.run_if(has_obstacles.and(tutorial_allows_obstacles)),
+                collide_obstacles,
+            )
+                .run_if(in_state(Nova::Idle)),
+            collide_obstacles.run_if(in_state(Nova::During)),
+            apply_obstacle_palette.run_if(resource_changed::<Palette>),
+        ),
+    );
+}
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct Obstacle;
+
+/// Sent whenever the player collides with something
+#[derive(Event, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PlayerCollision {
+    Obstacle,
+    BlackHole,
+}
+
+/// The locale key for why the run ended
+#[derive(Resource, Reflect)]
+#[reflect(Resource)]
+pub struct DeathReason(pub &'static str);
+
+impl Default for DeathReason {
+    fn default() -> Self {
+        Self(BURNED_UP)
+    }
+}
+
+/// Sent when the run ends
+#[derive(Event, Serialize, Debug, Clone, Copy)]
+pub struct PlayerDied {
+    /// The locale key for why the run ended
+    pub reason: &'static str,
+    pub score: f32,
+}
+
+fn reset_death_reason(mut death_reason: ResMut<DeathReason>) {
+    death_reason.0 = BURNED_UP;
+}
+
+fn announce_death(
+    death_reason: Res<DeathReason>,
+    score: Option<Res<Score>>,
+    mut died: EventWriter<PlayerDied>,
+) {
+    died.write(PlayerDied {
+        reason: death_reason.0,
+        score: score.map(|s| s.score).unwrap_or_default(),
+    });
+}
+
+/// When the waves of obstacles spawn, timed from the start of the run. Only
+/// the time spent spawning counts, so the waves pick up where they left off
+/// after each nova.
+#[derive(Resource, Reflect, Debug, Default)]
+#[reflect(Resource)]
+pub struct ObstacleSchedule {
+    /// How long obstacles have been spawning this run
+    pub elapsed: f32,
+    /// When the next wave spawns
+    pub next_wave: f32,
+}
+
+fn reset_obstacle_schedule(mut schedule: ResMut<ObstacleSchedule>) {
+    *schedule = ObstacleSchedule::default();
+}
+
+fn periodically_spawn_obstacles(
+    mut commands: Commands,
+    time: Res<Time>,
+    score: Res<Score>,
+    nova_state: Res<State<Nova>>,
+    daily: Option<Res<DailyChallenge>>,
+    mut run_rng: ResMut<RunRng>,
+    mut schedule: ResMut<ObstacleSchedule>,
+    player: Single<&ItemPosition, With<Player>>,
+) {
+    schedule.elapsed += time.delta_secs();
+    if schedule.elapsed < schedule.next_wave {
+        return;
+    }
+
+    let rng = &mut run_rng.obstacles;
+
+    // scheduled from the last wave rather than from now, so the waves don't
+    // drift with the frame rate
+    schedule.next_wave += rng.gen_range(0.1..0.4);
+    let num_obstacles = rng.gen_range(1..=3);
+    let radius = rng.gen_range(-75.0..(MAX_PLAYER_RADIUS * 0.5));
+    let angle = rng.gen_range(0.0..TAU);
+
+    let (theta, lifetime) = if daily.is_some() {
+        // everybody gets the same waves at the same places, wherever they are
+        // and however fast they go
+        (angle, TAU / PLAYER_STARTING_SPEED)
+    } else {
+        // account for the fact that the player speeds up over time. This should be
+        // sufficient for despawning on the other side of the world as the multiplier
+        // doesn't increase that quickly while the player is dodging.
+        let extra_speed =
+            utils::get_player_speed_multipliers(score.multiplier, &player, **nova_state);
+        let speed = player.speed * (extra_speed.0 + extra_speed.1);
+        (player.theta + PI, TAU / speed)
+    };
+
+    for _ in 0..num_obstacles {
+        commands.queue(SpawnObstacle {
+            radius: radius + rng.gen_range(-60.0..20.0),
+            theta: theta + rng.gen_range(-0.05..=0.05),
+            speed: rng.gen_range(-30.0..-15.0),
+            // destroy after one revolution
+            destroy_at: lifetime + time.elapsed_secs(),
+        });
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SpawnObstacle {
+    pub theta: f32,
+    /// The distance from the sun's surface
+    pub radius: f32,
+    pub destroy_at: f32,
+    pub speed: f32,
+}
+
+impl Command for SpawnObstacle {
+    fn apply(self, world: &mut World) {
+        let _ = world.run_system_cached_with(spawn_obstacle, self);
+    }
+}
+
+fn apply_obstacle_palette(
+    palette: Res<Palette>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    obstacles: Query<&MeshMaterial2d<ColorMaterial>, With<Obstacle>>,
+) {
+    for handle in &obstacles {
+        if let Some(material) = materials.get_mut(&handle.0) {
+            material.color = palette.obstacle;
+        }
+    }
+}
+
+fn spawn_obstacle(
+    config: In<SpawnObstacle>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    palette: Res<Palette>,
+    sun: Single<&Sun>,
+) {
+    // info!("Spawning obstacle");
+    let mesh = meshes.add(Rectangle::new(1.0, 15.0));
+
+    let radius = config.radius + sun.radius;
+    let theta = config.theta;
+    let translation = Vec3::new(radius * theta.sin(), radius * theta.cos(), -1.0);
+
+    commands.spawn((
+        Obstacle,
+        Transform::from_translation(translation).with_rotation(Quat::from_axis_angle(
+            Vec3::Z,
+            translation.truncate().to_angle() + std::f32::consts::FRAC_PI_2,
+        )),
+        RigidBody::Dynamic,
+        Collider::rectangle(4.0, 14.0),
+        LinearVelocity(-translation.truncate().normalize() * config.speed),
+        Sensor,
+        StateScoped(Screen::Gameplay),
+        DestroyAt {
+            time: config.destroy_at,
+        },
+        ItemPosition {
+            radius,
+            theta,
+            speed: 0.0,
+            center: Vec2::ZERO,
+        },
+        Visibility::Visible,
+        Mesh2d(mesh),
+        MeshMaterial2d(materials.add(palette.obstacle)),
+    ));
+}
+
+/// The spawner of the debris left when the player hits an obstacle
+#[derive(Debug, Component, Reflect)]
+#[reflect(Component)]
+pub struct AsteroidDebris;
+
+fn collide_obstacles(
+    mut commands: Commands,
+    player_assets: Option<Res<PlayerAssets>>,
+    mode: Res<GameMode>,
+    mut screen: ResMut<NextState<Screen>>,
+    mut death_reason: ResMut<DeathReason>,
+    mut collisions: EventWriter<PlayerCollision>,
+    colliders: Query<(Entity, &CollidingEntities)>,
+    obstacles: Query<&Transform, With<Obstacle>>,
+    warp_barriers: Query<(), With<BlackHole>>,
+    mut power: Single<(&mut PlayerPower, &mut PlayerShield, &ShipStats)>,
+    mut phase: Option<Single<(Entity, &mut Phase)>>,
+) {
+    for (_entity, colliding) in &colliders {
+        // the phase ends once the player is clear of the obstacle
+        if let Some((entity, phase)) = phase.as_deref()
+            && phase
+                .through
+                .is_some_and(|through| !colliding.contains(&through))
+        {
+            commands.entity(*entity).remove::<Phase>();
+        }
+
+        if colliding.is_empty() {
+            continue;
+        }
+
+        for collider in colliding.iter() {
+            if warp_barriers.get(*collider).is_ok() {
+                collisions.write(PlayerCollision::BlackHole);
+
+                if mode.can_die() {
+                    // uh oh we dead, can't go round hitting things in warp
+                    screen.set(Screen::GameOver);
+                    death_reason.0 = BLACK_HOLE;
+                } else {
+                    commands.entity(*collider).despawn();
+                }
+
+                if let Some(player_assets) = &player_assets {
+                    commands.spawn(SamplePlayer::new(player_assets.obstacle_hit.clone()));
+                }
+            }
+
+            if let Ok(tx) = obstacles.get(*collider) {
+                if let Some((_, phase)) = phase.as_deref_mut()
+                    && phase.through.is_none_or(|through| through == *collider)
+                {
+                    phase.through = Some(*collider);
+                    continue;
+                }
+
+                collisions.write(PlayerCollision::Obstacle);
+
+                power.0.0 = (power.0.0 - 25.0).clamp(0.0, 100.0);
+                let cost = power.2.obstacle_shield_cost;
+                let shield = &mut power.1;
+                shield.current = (shield.current - cost).clamp(0.0, shield.max);
+
+                // create a particle effect
+                let mut new_tx = tx.translation;
+                new_tx.z = 0.2;
+
+                commands.queue(
+                    PlayParticles::new(Effect::AsteroidHit, new_tx)
+                        .with((AsteroidDebris, GravityScale(DEBRIS_GRAVITY_SCALE))),
+                );
+
+                // destroy obstacle
+                commands.entity(*collider).despawn();
+
+                if let Some(player_assets) = &player_assets {
+                    commands.spawn(SamplePlayer::new(player_assets.obstacle_hit.clone()));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,207 @@
+//! Rewards the player for threading closely past obstacles without touching
+//! them, with a bigger bonus for each near miss in a chain.
+
+use avian2d::{collision::collider::contact_query, prelude::*};
+use bevy::prelude::*;
+use bevy_seedling::prelude::{PlaybackSettings, SamplePlayer, Volume};
+
+use crate::{
+    PlayerAssets,
+    locale::Locale,
+    obstacle::{Obstacle, PlayerCollision},
+    player::Player,
+    score::Score,
+    screens::Screen,
+    supernova::Nova,
+    utils::{DestroyAt, MoveInDirection, ScaleTextOverTime},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<NearMissConfig>();
+    app.register_type::<NearMissChain>();
+    app.register_type::<Grazing>();
+
+    app.init_resource::<NearMissConfig>();
+    app.init_resource::<NearMissChain>();
+
+    app.add_systems(OnEnter(Screen::Gameplay), reset_chain);
+    app.add_systems(
+        Update,
+        (break_chain, detect_near_misses)
+            .chain()
+            .run_if(in_state(Screen::Gameplay).and(in_state(Nova::Idle))),
+    );
+}
+
+#[derive(Resource, Reflect, Debug)]
+#[reflect(Resource)]
+pub struct NearMissConfig {
+    /// How close an obstacle has to pass to the player's collider to count
+    pub margin: f32,
+    /// The score for a single near miss, before the chain and multiplier
+    pub bonus: f32,
+    /// Seconds allowed between near misses before the chain is broken
+    pub chain_window: f32,
+}
+
+impl Default for NearMissConfig {
+    fn default() -> Self {
+        Self {
+            margin: 12.0,
+            bonus: 10.0,
+            chain_window: 3.0,
+        }
+    }
+}
+
+#[derive(Resource, Reflect, Debug, Default)]
+#[reflect(Resource)]
+pub struct NearMissChain {
+    pub count: u32,
+    /// The time of the last near miss
+    last: f32,
+}
+
+/// Added to obstacles while they are within the near miss margin of the
+/// player, the near miss is awarded once they leave it untouched
+#[derive(Component, Reflect, Debug, Default)]
+#[reflect(Component)]
+struct Grazing {
+    touched: bool,
+}
+
+fn reset_chain(mut chain: ResMut<NearMissChain>) {
+    *chain = NearMissChain::default();
+}
+
+/// Breaks the chain if the player hits something or waits too long
+fn break_chain(
+    time: Res<Time>,
+    config: Res<NearMissConfig>,
+    mut chain: ResMut<NearMissChain>,
+    mut collisions: EventReader<PlayerCollision>,
+) {
+    let hit = collisions.read().count() > 0;
+
+    if hit || time.elapsed_secs() - chain.last > config.chain_window {
+        chain.count = 0;
+    }
+}
+
+fn detect_near_misses(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Res<NearMissConfig>,
+    locale: Res<Locale>,
+    player_assets: Res<PlayerAssets>,
+    mut score: ResMut<Score>,
+    mut chain: ResMut<NearMissChain>,
+    player: Single<(&Collider, &Position, &Rotation, &Transform), With<Player>>,
+    mut obstacles: Query<
+        (
+            Entity,
+            &Collider,
+            &Position,
+            &Rotation,
+            Option<&mut Grazing>,
+        ),
+        With<Obstacle>,
+    >,
+) {
+    let (player_collider, player_position, player_rotation, player_tx) = player.into_inner();
+
+    for (entity, collider, position, rotation, grazing) in &mut obstacles {
+        let Ok(distance) = contact_query::distance(
+            player_collider,
+            *player_position,
+            *player_rotation,
+            collider,
+            *position,
+            *rotation,
+        ) else {
+            continue;
+        };
+
+        let within_margin = distance <= config.margin;
+
+        match grazing {
+            Some(mut grazing) if within_margin => {
+                grazing.touched |= distance <= 0.0;
+            }
+            Some(grazing) => {
+                commands.entity(entity).remove::<Grazing>();
+
+                if grazing.touched {
+                    continue;
+                }
+
+                chain.count += 1;
+                chain.last = time.elapsed_secs();
+
+                let bonus = config.bonus * chain.count as f32 * score.multiplier as f32;
+                score.score += bonus;
+
+                spawn_near_miss_feedback(
+                    &mut commands,
+                    &time,
+                    &locale,
+                    &player_assets,
+                    player_tx,
+                    chain.count,
+                    bonus,
+                );
+            }
+            None if within_margin => {
+                commands.entity(entity).insert(Grazing {
+                    touched: distance <= 0.0,
+                });
+            }
+            None => {}
+        }
+    }
+}
+
+fn spawn_near_miss_feedback(
+    commands: &mut Commands,
+    time: &Time,
+    locale: &Locale,
+    player_assets: &PlayerAssets,
+    player_tx: &Transform,
+    count: u32,
+    bonus: f32,
+) {
+    let bonus = format!("{bonus:.0}");
+    let text = if count > 1 {
+        locale.format(
+            "hud.near_miss_chain",
+            &[("count", &count), ("bonus", &bonus)],
+        )
+    } else {
+        locale.format("hud.near_miss", &[("bonus", &bonus)])
+    };
+
+    commands.spawn((
+        StateScoped(Screen::Gameplay),
+        Text2d::new(text),
+        TextFont::from_font_size(16.0),
+        Transform::from_translation(player_tx.translation),
+        TextColor(Color::srgba(0.4, 2.5, 3.0, 1.0)),
+        ScaleTextOverTime {
+            rate: 12.0,
+            max: 28.0,
+        },
+        MoveInDirection(player_tx.translation.truncate().normalize() * 80.0),
+        DestroyAt {
+            time: time.elapsed_secs() + 2.0,
+        },
+    ));
+
+    // pitched up as the chain grows
+    commands.spawn((
+        SamplePlayer::new(player_assets.near_miss.clone()).with_volume(Volume::Linear(0.6)),
+        PlaybackSettings {
+            speed: 1.0 + 0.1 * count.min(10) as f64,
+            ..default()
+        },
+    ));
+}
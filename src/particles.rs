@@ -0,0 +1,317 @@
+//! The particle effects, preloaded and scaled to the particle quality setting.
+//!
+//! One-shot effects are played with [`PlayParticles`], which reuses a finished
+//! spawner rather than spawning a new one each time.
+
+use bevy::prelude::*;
+use bevy_enoki::{
+    NoAutoAabb, Particle2dEffect, ParticleEffectHandle, ParticleSpawner,
+    prelude::{
+        OneShot, ParticleEffectInstance, ParticleSpawnerState, ParticleStore,
+        SpriteParticle2dMaterial,
+    },
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{asset_tracking::LoadResource, save::SaveData, screens::Screen};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<ParticleQuality>();
+    app.register_type::<ParticleAssets>();
+    app.register_type::<PooledParticles>();
+
+    app.load_resource::<ParticleAssets>();
+
+    app.add_systems(
+        Update,
+        (
+            prepare_effects.run_if(resource_added::<ParticleAssets>),
+            apply_particle_quality
+                .run_if(resource_exists::<ParticleEffects>.and(resource_changed::<SaveData>)),
+        )
+            .chain(),
+    );
+}
+
+/// How many particles the effects spawn, lowered for slower devices
+#[derive(Reflect, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParticleQuality {
+    Low,
+    Medium,
+    High,
+}
+
+impl Default for ParticleQuality {
+    fn default() -> Self {
+        // the web build is the one most likely to be played on a phone
+        if cfg!(target_family = "wasm") {
+            ParticleQuality::Medium
+        } else {
+            ParticleQuality::High
+        }
+    }
+}
+
+impl ParticleQuality {
+    /// The locale key for the quality's name
+    pub fn name_key(&self) -> &'static str {
+        match self {
+            ParticleQuality::Low => "particles.low",
+            ParticleQuality::Medium => "particles.medium",
+            ParticleQuality::High => "particles.high",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            ParticleQuality::Low => ParticleQuality::Medium,
+            ParticleQuality::Medium => ParticleQuality::High,
+            ParticleQuality::High => ParticleQuality::Low,
+        }
+    }
+
+    pub fn previous(&self) -> Self {
+        match self {
+            ParticleQuality::Low => ParticleQuality::High,
+            ParticleQuality::Medium => ParticleQuality::Low,
+            ParticleQuality::High => ParticleQuality::Medium,
+        }
+    }
+
+    /// Scales the number of particles each effect spawns
+    fn spawn_scale(&self) -> f32 {
+        match self {
+            ParticleQuality::Low => 0.3,
+            ParticleQuality::Medium => 0.6,
+            ParticleQuality::High => 1.0,
+        }
+    }
+
+    /// The most one-shot spawners of each effect that can be playing at
+    /// once. Any more are skipped.
+    fn max_pooled(&self) -> usize {
+        match self {
+            ParticleQuality::Low => 3,
+            ParticleQuality::Medium => 6,
+            ParticleQuality::High => 12,
+        }
+    }
+
+    fn spawn_amount(&self, amount: u32) -> u32 {
+        ((amount as f32 * self.spawn_scale()).round() as u32).max(1)
+    }
+}
+
+/// The effects as loaded, before scaling to the quality setting
+#[derive(Resource, Asset, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct ParticleAssets {
+    #[dependency]
+    asteroid_hit: Handle<Particle2dEffect>,
+    #[dependency]
+    rocket_trail: Handle<Particle2dEffect>,
+    #[dependency]
+    circle: Handle<Image>,
+}
+
+impl FromWorld for ParticleAssets {
+    fn from_world(world: &mut World) -> Self {
+        let assets = world.resource::<AssetServer>();
+        Self {
+            asteroid_hit: assets.load("particles/asteroid_hit.ron"),
+            rocket_trail: assets.load("particles/rocket_trail.ron"),
+            circle: assets.load("particles/circle.png"),
+        }
+    }
+}
+
+/// The effects that can be played
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Effect {
+    AsteroidHit,
+    RocketTrail,
+}
+
+impl Effect {
+    const ALL: [Effect; 2] = [Effect::AsteroidHit, Effect::RocketTrail];
+
+    fn loaded(self, assets: &ParticleAssets) -> &Handle<Particle2dEffect> {
+        match self {
+            Effect::AsteroidHit => &assets.asteroid_hit,
+            Effect::RocketTrail => &assets.rocket_trail,
+        }
+    }
+}
+
+/// Copies of the loaded effects with the spawn amounts scaled to the
+/// quality setting, which every spawner uses
+#[derive(Resource)]
+pub struct ParticleEffects {
+    quality: ParticleQuality,
+    asteroid_hit: Handle<Particle2dEffect>,
+    rocket_trail: Handle<Particle2dEffect>,
+    /// The sprite the rocket trail's particles are drawn with
+    pub rocket_trail_sprite: Handle<SpriteParticle2dMaterial>,
+}
+
+impl ParticleEffects {
+    pub fn get(&self, effect: Effect) -> Handle<Particle2dEffect> {
+        match effect {
+            Effect::AsteroidHit => self.asteroid_hit.clone(),
+            Effect::RocketTrail => self.rocket_trail.clone(),
+        }
+    }
+}
+
+fn prepare_effects(
+    mut commands: Commands,
+    assets: Res<ParticleAssets>,
+    save: Res<SaveData>,
+    mut effects: ResMut<Assets<Particle2dEffect>>,
+    mut sprites: ResMut<Assets<SpriteParticle2dMaterial>>,
+) {
+    let quality = save.particle_quality;
+    let mut scaled = |effect: Effect| {
+        let mut copy = effects
+            .get(effect.loaded(&assets))
+            .expect("effects are loaded before the resource is added")
+            .clone();
+        copy.spawn_amount = quality.spawn_amount(copy.spawn_amount);
+        effects.add(copy)
+    };
+
+    commands.insert_resource(ParticleEffects {
+        quality,
+        asteroid_hit: scaled(Effect::AsteroidHit),
+        rocket_trail: scaled(Effect::RocketTrail),
+        rocket_trail_sprite: sprites.add(SpriteParticle2dMaterial::new(
+            assets.circle.clone(),
+            1,
+            1,
+        )),
+    });
+}
+
+/// Rescales the effects when the quality setting changes, including those
+/// already playing
+fn apply_particle_quality(
+    save: Res<SaveData>,
+    assets: Res<ParticleAssets>,
+    mut scaled: ResMut<ParticleEffects>,
+    mut effects: ResMut<Assets<Particle2dEffect>>,
+    mut spawners: Query<(&ParticleEffectHandle, &mut ParticleEffectInstance)>,
+) {
+    let quality = save.particle_quality;
+    if scaled.quality == quality {
+        return;
+    }
+    scaled.quality = quality;
+
+    for effect in Effect::ALL {
+        let Some(amount) = effects
+            .get(effect.loaded(&assets))
+            .map(|loaded| quality.spawn_amount(loaded.spawn_amount))
+        else {
+            continue;
+        };
+        let handle = scaled.get(effect);
+        if let Some(copy) = effects.get_mut(&handle) {
+            copy.spawn_amount = amount;
+        }
+
+        for (_, mut instance) in spawners.iter_mut().filter(|(h, _)| h.0 == handle) {
+            if let Some(instance) = instance.0.as_mut() {
+                instance.spawn_amount = amount;
+            }
+        }
+    }
+}
+
+/// A one-shot spawner that is reused once its particles are gone
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+pub struct PooledParticles(Effect);
+
+/// Plays a one-shot effect, with `bundle` added to its spawner
+#[derive(Debug)]
+pub struct PlayParticles<B: Bundle = ()> {
+    pub effect: Effect,
+    pub translation: Vec3,
+    pub bundle: B,
+}
+
+impl PlayParticles {
+    pub fn new(effect: Effect, translation: Vec3) -> Self {
+        Self {
+            effect,
+            translation,
+            bundle: (),
+        }
+    }
+}
+
+impl<B: Bundle> PlayParticles<B> {
+    pub fn with<C: Bundle>(self, bundle: C) -> PlayParticles<C> {
+        PlayParticles {
+            effect: self.effect,
+            translation: self.translation,
+            bundle,
+        }
+    }
+}
+
+impl<B: Bundle> Command for PlayParticles<B> {
+    fn apply(self, world: &mut World) {
+        let _ = world.run_system_cached_with(play_particles::<B>, self);
+    }
+}
+
+fn play_particles<B: Bundle>(
+    In(play): In<PlayParticles<B>>,
+    mut commands: Commands,
+    save: Res<SaveData>,
+    effects: Option<Res<ParticleEffects>>,
+    mut pool: Query<(
+        Entity,
+        &PooledParticles,
+        &mut ParticleSpawnerState,
+        &ParticleStore,
+        &mut Transform,
+    )>,
+) {
+    let Some(effects) = effects else {
+        return;
+    };
+
+    let mut playing = 0;
+    for (entity, pooled, mut state, store, mut transform) in &mut pool {
+        if pooled.0 != play.effect {
+            continue;
+        }
+        if state.active || !store.is_empty() {
+            playing += 1;
+            continue;
+        }
+
+        transform.translation = play.translation;
+        state.active = true;
+        commands.entity(entity).insert(play.bundle);
+        return;
+    }
+
+    if playing >= save.particle_quality.max_pooled() {
+        return;
+    }
+
+    commands.spawn((
+        Name::new("Pooled Particles"),
+        PooledParticles(play.effect),
+        StateScoped(Screen::Gameplay),
+        Transform::from_translation(play.translation),
+        ParticleSpawner::default(),
+        ParticleEffectHandle(effects.get(play.effect)),
+        OneShot::Deactivate,
+        NoAutoAabb,
+        play.bundle,
+    ));
+}
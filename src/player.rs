@@ -1,344 +1,442 @@
-use avian2d::prelude::*;
-use bevy::{color::palettes::css::WHITE, prelude::*};
-use bevy_enoki::{
-    NoAutoAabb, ParticleEffectHandle, ParticleSpawner,
-    prelude::{ParticleSpawnerState, SpriteParticle2dMaterial},
-};
-use bevy_seedling::sample::SamplePlayer;
-
-use crate::{
-    consts::{MAX_PLAYER_RADIUS, PLAYER_STARTING_SPEED},
-    input::PlayerInputAngle,
-    materials::BarDataSource,
-    score::Score,
-    screens::Screen,
-    sun::Sun,
-    supernova::Nova,
-    utils::get_player_speed_multipliers,
-};
-
-mod assets;
-pub use assets::PlayerAssets;
-mod trail;
-
-pub(super) fn plugin(app: &mut App) {
-    app.register_type::<ItemPosition>();
-    app.register_type::<Player>();
-    app.register_type::<PlayerEffects>();
-    app.register_type::<PlayerPower>();
-    app.register_type::<ShieldAlarm>();
-
-    app.add_plugins((assets::plugin, trail::TrailPlugin::<12>));
-
-    app.add_systems(OnEnter(Screen::Gameplay), spawn_player);
-    app.add_systems(
-        PreUpdate,
-        (update_player_theta, set_player_position)
-            .chain()
-            .run_if(in_state(Screen::Gameplay)),
-    );
-    app.add_systems(OnExit(Nova::Idle), ensure_trail_particles_deactivate);
-    app.add_systems(
-        Update,
-        (
-            shield_decay,
-            power_generation,
-            (shield_monitor, activate_trail_particles_on_sun).run_if(in_state(Nova::Idle)),
-        )
-            .run_if(in_state(Screen::Gameplay)),
-    );
-    app.add_systems(
-        PostUpdate,
-        camera_follow_player.run_if(in_state(Screen::Gameplay)),
-    );
-    app.add_systems(OnExit(Screen::Gameplay), reset_camera_position);
-}
-
-#[derive(Component, Reflect)]
-#[reflect(Component)]
-pub struct Player;
-
-#[derive(Component, Reflect)]
-#[reflect(Component)]
-pub struct PlayerEffects;
-
-#[derive(Component, Reflect, Default)]
-#[reflect(Component)]
-pub struct PlayerPower(pub f32);
-
-impl BarDataSource for PlayerPower {
-    fn current_frac(&self) -> f32 {
-        (self.0 / 100.0).clamp(0.0, 100.0)
-    }
-}
-
-#[derive(Component, Reflect)]
-#[reflect(Component)]
-pub struct PlayerShield(pub f32);
-
-impl Default for PlayerShield {
-    fn default() -> Self {
-        Self(100.0)
-    }
-}
-
-impl BarDataSource for PlayerShield {
-    fn current_frac(&self) -> f32 {
-        (self.0 / 100.0).clamp(0.0, 100.0)
-    }
-}
-
-#[derive(Component, Debug, Reflect, Clone, Copy)]
-#[reflect(Component)]
-pub struct ItemPosition {
-    // The distance from the sun edge
-    pub radius: f32,
-    pub theta: f32,
-    pub speed: f32,
-    pub center: Vec2,
-}
-
-impl Default for ItemPosition {
-    fn default() -> Self {
-        Self {
-            radius: 250.0,
-            speed: PLAYER_STARTING_SPEED,
-            theta: 0.0,
-            center: Vec2::ZERO,
-        }
-    }
-}
-
-fn spawn_player(
-    mut commands: Commands,
-    asset_server: Res<AssetServer>,
-    player_assets: Res<PlayerAssets>,
-    mut particle_materials: ResMut<Assets<SpriteParticle2dMaterial>>,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<ColorMaterial>>,
-) {
-    let player_mesh = meshes.add(Triangle2d::new(
-        Vec2::Y * 10.0,
-        Vec2::new(-5.0, -5.0),
-        Vec2::new(5.0, -5.0),
-    ));
-
-    let color = Color::srgba(3.0, 3.0, 3.0, 1.0);
-    let particle_sprite = particle_materials.add(SpriteParticle2dMaterial::new(
-        player_assets.rocket_trail_particle.clone(),
-        1,
-        1,
-    ));
-
-    commands.spawn((
-        Mesh2d(player_mesh),
-        MeshMaterial2d(materials.add(color)),
-        Transform::from_xyz(0.0, 0.0, 0.1),
-        StateScoped(Screen::Gameplay),
-        Player,
-        PlayerPower::default(),
-        PlayerShield::default(),
-        ItemPosition::default(),
-        RigidBody::Kinematic,
-        Collider::capsule(4.5, 9.0),
-        Sensor,
-        CollidingEntities::default(),
-        children![(
-            Transform::from_translation(Vec3::new(0.0, 0.0, -0.3)),
-            ParticleSpawner(particle_sprite),
-            ParticleEffectHandle(asset_server.load("particles/rocket_trail.ron")),
-            ParticleSpawnerState {
-                active: false,
-                ..default()
-            },
-            NoAutoAabb,
-            PlayerEffects
-        )],
-    ));
-}
-
-fn ensure_trail_particles_deactivate(
-    mut effect_state: Single<&mut ParticleSpawnerState, With<PlayerEffects>>,
-) {
-    effect_state.active = false;
-}
-
-fn activate_trail_particles_on_sun(
-    player_pos: Single<&ItemPosition, With<Player>>,
-    mut effect_state: Single<&mut ParticleSpawnerState, With<PlayerEffects>>,
-) {
-    effect_state.active = player_pos.radius < 1.0;
-}
-
-fn update_player_theta(
-    time: Res<Time>,
-    nova: Option<Res<State<Nova>>>,
-    score: Option<Res<Score>>,
-    mut player: Single<&mut ItemPosition, With<Player>>,
-) {
-    let (speed_multiplier, level_multiplier) = if let Some(nova) = nova {
-        let multiplier = if let Some(score) = score {
-            score.multiplier
-        } else {
-            0
-        };
-        get_player_speed_multipliers(multiplier, &player, **nova)
-    } else {
-        (1.0, 0.0)
-    };
-
-    player.theta += player.speed * time.delta_secs() * (speed_multiplier + level_multiplier);
-}
-
-fn set_player_position(
-    player_angle: Res<PlayerInputAngle>,
-    sun: Single<&Sun>,
-    mut player: Single<(&mut Transform, &ItemPosition), With<Player>>,
-) {
-    let radius = sun.radius + player.1.radius;
-
-    player.0.translation = Vec3::new(
-        radius * player.1.theta.sin(),
-        radius * player.1.theta.cos(),
-        0.1,
-    );
-
-    let extra_angle = if player.1.radius <= 1.0 || player.1.radius >= MAX_PLAYER_RADIUS {
-        0.0
-    } else {
-        player_angle.0 * 0.3
-    };
-
-    player.0.rotation = Quat::from_axis_angle(
-        Vec3::Z,
-        player.0.translation.truncate().to_angle() + std::f32::consts::PI + extra_angle,
-    );
-}
-
-fn camera_follow_player(
-    mut cameras: Query<&mut Transform, (Without<Player>, With<Camera2d>)>,
-    player: Single<Ref<Transform>, With<Player>>,
-) {
-    for mut camera in &mut cameras {
-        camera.translation = player.translation;
-    }
-}
-
-fn reset_camera_position(mut cameras: Query<&mut Transform, With<Camera2d>>) {
-    for mut tx in &mut cameras {
-        tx.translation = Vec3::ZERO;
-    }
-}
-
-fn power_generation(
-    time: Res<Time>,
-    nova: Option<Res<State<Nova>>>,
-    mut player: Single<(&ItemPosition, &mut PlayerPower)>,
-) {
-    let distance = player.0.radius;
-
-    player.1.0 = match nova {
-        Some(ns) => match **ns {
-            Nova::Idle => {
-                let power = -0.05 * distance + 14.0;
-                player.1.0 + time.delta_secs() * power.clamp(-3.0, 10.0)
-            }
-            Nova::BuildingUp | Nova::During => 99.0,
-            Nova::After => 0.0,
-        },
-        None => {
-            let power = -0.05 * distance + 14.0;
-            player.1.0 + time.delta_secs() * power.clamp(-3.0, 10.0)
-        }
-    };
-}
-
-fn shield_decay(
-    time: Res<Time>,
-    nova: Option<Res<State<Nova>>>,
-    mut player: Single<(&ItemPosition, &mut PlayerShield)>,
-) {
-    let distance = player.0.radius;
-    let rate = if nova.is_none() || matches!(**nova.unwrap(), Nova::Idle) {
-        // decay when close to the sun
-        if distance > 55.0 {
-            0.3 * distance + 23.0 // linear after intersection
-        } else {
-            -2.0 / (0.005 * distance)
-        }
-    } else {
-        // just recharge when not in nova
-        0.3 * distance + 23.0
-    };
-
-    player.1.0 = (player.1.0 + time.delta_secs() * rate.clamp(-10.0, 10.0)).clamp(0.0, 100.0);
-}
-
-#[derive(Resource, Debug, Reflect)]
-#[reflect(Resource)]
-pub struct ShieldAlarm(Entity, Entity);
-
-fn shield_monitor(
-    mut commands: Commands,
-    maybe_alarm: Option<Res<ShieldAlarm>>,
-    player_assets: Res<PlayerAssets>,
-    mut next_state: ResMut<NextState<Screen>>,
-    shield: Single<&PlayerShield>,
-) {
-    if shield.0 < 0.1 {
-        next_state.set(Screen::GameOver);
-        return;
-    }
-
-    if shield.0 < 25.0 {
-        if maybe_alarm.is_none() {
-            let alarm = commands
-                .spawn((
-                    SamplePlayer::new(player_assets.shield_alert.clone())
-                        .looping()
-                        .with_volume(bevy_seedling::prelude::Volume::Linear(1.0)),
-                    StateScoped(Screen::Gameplay),
-                ))
-                .id();
-            let text = commands
-                .spawn((
-                    StateScoped(Nova::Idle),
-                    Node {
-                        position_type: PositionType::Absolute,
-                        top: Val::ZERO,
-                        left: Val::ZERO,
-                        width: Val::Percent(100.0),
-                        height: Val::Percent(100.0),
-                        justify_content: JustifyContent::Center,
-                        align_items: AlignItems::Center,
-                        ..default()
-                    },
-                    children![(
-                        Node {
-                            position_type: PositionType::Relative,
-                            top: Val::Px(25.0),
-                            ..default()
-                        },
-                        Text::new("SHIELD LOW"),
-                        TextLayout {
-                            justify: JustifyText::Center,
-                            ..default()
-                        },
-                        TextFont {
-                            font_size: 12.0,
-                            ..default()
-                        },
-                        TextColor(WHITE.into()),
-                    )],
-                ))
-                .id();
-
-            commands.insert_resource(ShieldAlarm(alarm, text));
-        }
-    } else if maybe_alarm.is_some() {
-        let alarm = maybe_alarm.unwrap();
-        commands.entity(alarm.0).despawn();
-        commands.entity(alarm.1).despawn();
-        commands.remove_resource::<ShieldAlarm>();
-    }
-}
+use avian2d::prelude::*;
+use bevy::{color::palettes::css::WHITE, prelude::*};
+use bevy_enoki::{
+    NoAutoAabb, ParticleEffectHandle, ParticleSpawner, prelude::ParticleSpawnerState,
+};
+use bevy_seedling::sample::SamplePlayer;
+use serde::Serialize;
+
+use crate::{
+    abilities::{BOOST_SPEED_MULTIPLIER, Boost},
+    combo::SkimCombo,
+    consts::{
+        MAX_PLAYER_RADIUS, PLAYER_MAX_SHIELD, PLAYER_RADIUS_CHANGE_SPEED, PLAYER_STARTING_SPEED,
+        PLAYER_TRAIL_COLOR, PLAYER_TRAIL_LENGTH, PLAYER_TRAIL_LIFETIME,
+        SHIELD_COST_ON_OBSTACLE_HIT,
+    },
+    input::PlayerInputAngle,
+    locale::UiText,
+    materials::BarDataSource,
+    mode::GameMode,
+    particles::{Effect, ParticleEffects},
+    save::SaveData,
+    score::Score,
+    screens::Screen,
+    sun::Sun,
+    supernova::Nova,
+    trail::Trail,
+    utils::get_player_speed_multipliers,
+};
+
+mod assets;
+pub use assets::PlayerAssets;
+mod hull;
+pub use hull::Hull;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<ItemPosition>();
+    app.register_type::<Player>();
+    app.register_type::<PlayerEffects>();
+    app.register_type::<PlayerPower>();
+    app.register_type::<ShieldAlarm>();
+    app.register_type::<ShipStats>();
+
+    app.add_event::<ShieldAlarmChanged>();
+
+    app.add_plugins((assets::plugin, hull::plugin));
+
+    app.add_systems(OnEnter(Screen::Gameplay), spawn_player);
+    app.add_systems(OnExit(Screen::Gameplay), remove_shield_alarm);
+    app.add_systems(
+        PreUpdate,
+        (update_player_theta, set_player_position)
+            .chain()
+            .run_if(in_state(Screen::Gameplay)),
+    );
+    app.add_systems(OnExit(Nova::Idle), ensure_trail_particles_deactivate);
+    app.add_systems(OnEnter(Nova::Idle), restart_trail);
+    app.add_systems(OnExit(Nova::BuildingUp), stop_trail);
+    app.add_systems(
+        Update,
+        (
+            shield_decay,
+            power_generation,
+            (shield_monitor, activate_trail_particles_on_sun).run_if(in_state(Nova::Idle)),
+        )
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct Player;
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct PlayerEffects;
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct PlayerPower(pub f32);
+
+impl BarDataSource for PlayerPower {
+    fn current_frac(&self) -> f32 {
+        (self.0 / 100.0).clamp(0.0, 100.0)
+    }
+}
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct PlayerShield {
+    pub current: f32,
+    pub max: f32,
+}
+
+impl PlayerShield {
+    pub fn new(max: f32) -> Self {
+        Self { current: max, max }
+    }
+}
+
+impl Default for PlayerShield {
+    fn default() -> Self {
+        Self::new(PLAYER_MAX_SHIELD)
+    }
+}
+
+impl BarDataSource for PlayerShield {
+    fn current_frac(&self) -> f32 {
+        (self.current / self.max).clamp(0.0, 1.0)
+    }
+}
+
+/// How the player's ship handles, after any upgrades are applied
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct ShipStats {
+    /// How quickly the ship orbits the sun
+    pub speed: f32,
+    pub max_shield: f32,
+    /// The shield lost when hitting an obstacle
+    pub obstacle_shield_cost: f32,
+    /// How quickly the ship moves towards or away from the sun
+    pub radius_change_speed: f32,
+    /// Scales the power generated while close to the sun
+    pub power_generation: f32,
+    /// Scales the shield lost while skimming the sun
+    pub heat_damage: f32,
+}
+
+impl Default for ShipStats {
+    fn default() -> Self {
+        Self {
+            speed: PLAYER_STARTING_SPEED,
+            max_shield: PLAYER_MAX_SHIELD,
+            obstacle_shield_cost: SHIELD_COST_ON_OBSTACLE_HIT,
+            radius_change_speed: PLAYER_RADIUS_CHANGE_SPEED,
+            power_generation: 1.0,
+            heat_damage: 1.0,
+        }
+    }
+}
+
+#[derive(Component, Debug, Reflect, Clone, Copy)]
+#[reflect(Component)]
+pub struct ItemPosition {
+    // The distance from the sun edge
+    pub radius: f32,
+    pub theta: f32,
+    pub speed: f32,
+    pub center: Vec2,
+}
+
+impl Default for ItemPosition {
+    fn default() -> Self {
+        Self {
+            radius: 250.0,
+            speed: PLAYER_STARTING_SPEED,
+            theta: 0.0,
+            center: Vec2::ZERO,
+        }
+    }
+}
+
+fn spawn_player(
+    mut commands: Commands,
+    particle_effects: Res<ParticleEffects>,
+    save: Res<SaveData>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    let hull = save.hull;
+    let mut stats = hull.stats();
+    save.upgrades.apply(&mut stats);
+
+    let player_mesh = meshes.add(hull.mesh());
+    let (collider_radius, collider_length) = hull.collider_size();
+
+    commands.spawn((
+        Mesh2d(player_mesh),
+        MeshMaterial2d(materials.add(hull.color())),
+        Transform::from_xyz(0.0, 0.0, 0.1),
+        StateScoped(Screen::Gameplay),
+        Player,
+        PlayerPower::default(),
+        SkimCombo::default(),
+        PlayerShield::new(stats.max_shield),
+        ItemPosition {
+            speed: stats.speed,
+            ..default()
+        },
+        stats,
+        (
+            RigidBody::Kinematic,
+            Collider::capsule(collider_radius, collider_length),
+            Sensor,
+            CollidingEntities::default(),
+        ),
+        Trail::new(PLAYER_TRAIL_LENGTH, PLAYER_TRAIL_LIFETIME)
+            .with_width(3.0, 0.5)
+            .with_color(PLAYER_TRAIL_COLOR, PLAYER_TRAIL_COLOR.with_alpha(0.0)),
+        children![(
+            Transform::from_translation(Vec3::new(0.0, 0.0, -0.3)),
+            ParticleSpawner(particle_effects.rocket_trail_sprite.clone()),
+            ParticleEffectHandle(particle_effects.get(Effect::RocketTrail)),
+            ParticleSpawnerState {
+                active: false,
+                ..default()
+            },
+            NoAutoAabb,
+            PlayerEffects
+        )],
+    ));
+}
+
+/// Starts the trail afresh from wherever the ship is after a nova
+fn restart_trail(mut trails: Query<&mut Trail, With<Player>>) {
+    for mut trail in &mut trails {
+        trail.clear();
+        trail.emitting = true;
+    }
+}
+
+/// Hides the trail for the rest of the nova
+fn stop_trail(mut trails: Query<&mut Trail, With<Player>>) {
+    for mut trail in &mut trails {
+        trail.clear();
+        trail.emitting = false;
+    }
+}
+
+fn ensure_trail_particles_deactivate(
+    mut effect_state: Single<&mut ParticleSpawnerState, With<PlayerEffects>>,
+) {
+    effect_state.active = false;
+}
+
+fn activate_trail_particles_on_sun(
+    player_pos: Single<&ItemPosition, With<Player>>,
+    mut effect_state: Single<&mut ParticleSpawnerState, With<PlayerEffects>>,
+) {
+    effect_state.active = player_pos.radius < 1.0;
+}
+
+fn update_player_theta(
+    time: Res<Time>,
+    nova: Option<Res<State<Nova>>>,
+    score: Option<Res<Score>>,
+    player: Single<(&mut ItemPosition, Has<Boost>), With<Player>>,
+) {
+    let (mut player, boosting) = player.into_inner();
+    let (speed_multiplier, level_multiplier) = if let Some(nova) = nova {
+        let multiplier = if let Some(score) = score {
+            score.multiplier
+        } else {
+            0
+        };
+        get_player_speed_multipliers(multiplier, &player, **nova)
+    } else {
+        (1.0, 0.0)
+    };
+
+    let boost = if boosting {
+        BOOST_SPEED_MULTIPLIER
+    } else {
+        1.0
+    };
+
+    player.theta +=
+        player.speed * time.delta_secs() * (speed_multiplier + level_multiplier) * boost;
+}
+
+fn set_player_position(
+    player_angle: Res<PlayerInputAngle>,
+    sun: Single<&Sun>,
+    mut player: Single<(&mut Transform, &ItemPosition), With<Player>>,
+) {
+    let radius = sun.radius + player.1.radius;
+
+    player.0.translation = Vec3::new(
+        radius * player.1.theta.sin(),
+        radius * player.1.theta.cos(),
+        0.1,
+    );
+
+    let extra_angle = if player.1.radius <= 1.0 || player.1.radius >= MAX_PLAYER_RADIUS {
+        0.0
+    } else {
+        player_angle.0 * 0.3
+    };
+
+    player.0.rotation = Quat::from_axis_angle(
+        Vec3::Z,
+        player.0.translation.truncate().to_angle() + std::f32::consts::PI + extra_angle,
+    );
+}
+
+fn power_generation(
+    time: Res<Time>,
+    nova: Option<Res<State<Nova>>>,
+    mut player: Single<(&ItemPosition, &ShipStats, &mut PlayerPower)>,
+) {
+    let (position, stats, power) = &mut *player;
+    let distance = position.radius;
+
+    let generated = (-0.05 * distance + 14.0).clamp(-3.0, 10.0);
+    let generated = if generated > 0.0 {
+        generated * stats.power_generation
+    } else {
+        generated
+    };
+
+    power.0 = match nova {
+        Some(ns) => match **ns {
+            Nova::Idle => power.0 + time.delta_secs() * generated,
+            Nova::BuildingUp | Nova::During => 99.0,
+            Nova::After => 0.0,
+        },
+        None => power.0 + time.delta_secs() * generated,
+    };
+}
+
+fn shield_decay(
+    time: Res<Time>,
+    mode: Res<GameMode>,
+    nova: Option<Res<State<Nova>>>,
+    mut player: Single<(&ItemPosition, &ShipStats, &mut PlayerShield)>,
+) {
+    let (position, stats, shield) = &mut *player;
+    let distance = position.radius;
+    let rate = if nova.is_none() || matches!(**nova.unwrap(), Nova::Idle) {
+        // decay when close to the sun
+        if distance > 55.0 {
+            0.3 * distance + 23.0 // linear after intersection
+        } else {
+            -2.0 / (0.005 * distance)
+        }
+    } else {
+        // just recharge when not in nova
+        0.3 * distance + 23.0
+    };
+
+    let rate = if mode.damages_shield() {
+        rate
+    } else {
+        rate.max(0.0)
+    };
+
+    let rate = rate.clamp(-10.0, 10.0);
+    let rate = if rate < 0.0 {
+        rate * stats.heat_damage
+    } else {
+        rate
+    };
+
+    shield.current = (shield.current + time.delta_secs() * rate).clamp(0.0, shield.max);
+}
+
+#[derive(Resource, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct ShieldAlarm(Entity, Entity);
+
+/// Sent when the low shield alarm starts or stops
+#[derive(Event, Serialize, Debug, Clone, Copy)]
+pub struct ShieldAlarmChanged {
+    pub active: bool,
+}
+
+/// The alarm's entities are scoped to the run, but the resource pointing at
+/// them isn't
+fn remove_shield_alarm(mut commands: Commands) {
+    commands.remove_resource::<ShieldAlarm>();
+}
+
+fn shield_monitor(
+    mut commands: Commands,
+    mode: Res<GameMode>,
+    maybe_alarm: Option<Res<ShieldAlarm>>,
+    mut alarm_changed: EventWriter<ShieldAlarmChanged>,
+    player_assets: Res<PlayerAssets>,
+    mut next_state: ResMut<NextState<Screen>>,
+    shield: Single<&PlayerShield>,
+) {
+    if shield.current < 0.1 && mode.can_die() {
+        next_state.set(Screen::GameOver);
+        return;
+    }
+
+    if shield.current_frac() < 0.25 {
+        if maybe_alarm.is_none() {
+            let alarm = commands
+                .spawn((
+                    SamplePlayer::new(player_assets.shield_alert.clone())
+                        .looping()
+                        .with_volume(bevy_seedling::prelude::Volume::Linear(1.0)),
+                    StateScoped(Screen::Gameplay),
+                ))
+                .id();
+            let text = commands
+                .spawn((
+                    StateScoped(Nova::Idle),
+                    Node {
+                        position_type: PositionType::Absolute,
+                        top: Val::ZERO,
+                        left: Val::ZERO,
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(100.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    children![(
+                        Node {
+                            position_type: PositionType::Relative,
+                            top: Val::Px(25.0),
+                            ..default()
+                        },
+                        Text::default(),
+                        UiText::Key("hud.shield_low"),
+                        TextLayout {
+                            justify: JustifyText::Center,
+                            ..default()
+                        },
+                        TextFont {
+                            font_size: 12.0,
+                            ..default()
+                        },
+                        TextColor(WHITE.into()),
+                    )],
+                ))
+                .id();
+
+            commands.insert_resource(ShieldAlarm(alarm, text));
+            alarm_changed.write(ShieldAlarmChanged { active: true });
+        }
+    } else if maybe_alarm.is_some() {
+        let alarm = maybe_alarm.unwrap();
+        commands.entity(alarm.0).despawn();
+        commands.entity(alarm.1).despawn();
+        commands.remove_resource::<ShieldAlarm>();
+        alarm_changed.write(ShieldAlarmChanged { active: false });
+    }
+}
@@ -0,0 +1,95 @@
+//! The ship hulls the player can choose between, each with its own handling.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::ShipStats;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<Hull>();
+}
+
+#[derive(Reflect, Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Hull {
+    /// The all rounder the game was balanced around
+    #[default]
+    Standard,
+    /// Fast and nimble but runs hot and has a thin shield
+    Scout,
+    /// Slow and heavy, with a large shield that shrugs off the heat
+    Hauler,
+}
+
+impl Hull {
+    /// The locale key for the hull's name
+    pub fn name_key(&self) -> &'static str {
+        match self {
+            Hull::Standard => "hull.standard",
+            Hull::Scout => "hull.scout",
+            Hull::Hauler => "hull.hauler",
+        }
+    }
+
+    /// The locale key for the hull's description
+    pub fn description_key(&self) -> &'static str {
+        match self {
+            Hull::Standard => "hull.standard.description",
+            Hull::Scout => "hull.scout.description",
+            Hull::Hauler => "hull.hauler.description",
+        }
+    }
+
+    /// The stats of the hull before any upgrades are applied
+    pub fn stats(&self) -> ShipStats {
+        let standard = ShipStats::default();
+
+        match self {
+            Hull::Standard => standard,
+            Hull::Scout => ShipStats {
+                speed: standard.speed * 1.2,
+                max_shield: standard.max_shield * 0.75,
+                radius_change_speed: standard.radius_change_speed * 1.3,
+                heat_damage: 1.3,
+                ..standard
+            },
+            Hull::Hauler => ShipStats {
+                speed: standard.speed * 0.9,
+                max_shield: standard.max_shield * 1.4,
+                obstacle_shield_cost: standard.obstacle_shield_cost * 0.8,
+                radius_change_speed: standard.radius_change_speed * 0.75,
+                heat_damage: 0.7,
+                ..standard
+            },
+        }
+    }
+
+    /// The radius and length of the hull's capsule collider
+    pub fn collider_size(&self) -> (f32, f32) {
+        match self {
+            Hull::Standard => (4.5, 9.0),
+            Hull::Scout => (3.5, 9.0),
+            Hull::Hauler => (6.5, 9.0),
+        }
+    }
+
+    pub fn mesh(&self) -> Mesh {
+        match self {
+            Hull::Standard => {
+                Triangle2d::new(Vec2::Y * 10.0, Vec2::new(-5.0, -5.0), Vec2::new(5.0, -5.0)).into()
+            }
+            Hull::Scout => {
+                Triangle2d::new(Vec2::Y * 12.0, Vec2::new(-4.0, -6.0), Vec2::new(4.0, -6.0)).into()
+            }
+            Hull::Hauler => Rhombus::new(14.0, 20.0).into(),
+        }
+    }
+
+    /// The colour of the hull, bright enough to bloom
+    pub fn color(&self) -> Color {
+        match self {
+            Hull::Standard => Color::srgba(3.0, 3.0, 3.0, 1.0),
+            Hull::Scout => Color::srgba(1.5, 3.0, 3.5, 1.0),
+            Hull::Hauler => Color::srgba(3.5, 2.6, 1.4, 1.0),
+        }
+    }
+}
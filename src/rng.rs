@@ -0,0 +1,49 @@
+//! Seeded random number generation for gameplay, so that runs can be replayed
+//! with identical layouts.
+
+use bevy::prelude::*;
+use rand::{SeedableRng, rngs::StdRng};
+
+use crate::{daily::DailyChallenge, screens::Screen};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::Gameplay), seed_run_rng);
+}
+
+/// The random number generators for the current run. Obstacles and black holes
+/// draw from separate streams so that one doesn't shift the other's layout.
+#[derive(Resource)]
+pub struct RunRng {
+    /// The seed the run was started with
+    pub seed: u64,
+    pub obstacles: StdRng,
+    pub black_holes: StdRng,
+}
+
+impl RunRng {
+    pub fn from_seed(seed: u64) -> Self {
+        Self {
+            seed,
+            obstacles: StdRng::seed_from_u64(seed),
+            black_holes: StdRng::seed_from_u64(seed.wrapping_add(1)),
+        }
+    }
+}
+
+/// Forces the seed of every run, so simulations can be repeated
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct SeedOverride(pub u64);
+
+pub fn seed_run_rng(
+    mut commands: Commands,
+    seed_override: Option<Res<SeedOverride>>,
+    daily: Option<Res<DailyChallenge>>,
+) {
+    let seed = match (seed_override, daily) {
+        (Some(seed_override), _) => seed_override.0,
+        (None, Some(daily)) => daily.day,
+        (None, None) => rand::random(),
+    };
+
+    commands.insert_resource(RunRng::from_seed(seed));
+}
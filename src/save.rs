@@ -0,0 +1,124 @@
+//! Player data that persists between sessions.
+//!
+//! Native builds write a RON file to the working directory, web builds
+//! use the browser's local storage.
+
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    camera::CameraSettings,
+    locale::Language,
+    particles::ParticleQuality,
+    player::Hull,
+    theme::{layout::UiScaleSetting, palette::PaletteTheme},
+    upgrades::Upgrades,
+};
+
+const SAVE_KEY: &str = "nova_looper_save";
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<SaveData>();
+
+    if app.world().contains_resource::<InMemorySave>() {
+        app.init_resource::<SaveData>();
+        return;
+    }
+
+    app.insert_resource(SaveData::load());
+
+    app.add_systems(
+        Last,
+        write_save_data.run_if(resource_changed::<SaveData>.and(not(resource_added::<SaveData>))),
+    );
+}
+
+/// Starts from fresh save data and never writes it, so simulations and tests
+/// don't touch the player's progress. Must be inserted before this plugin is
+/// added.
+#[derive(Resource)]
+pub struct InMemorySave;
+
+#[derive(Resource, Reflect, Serialize, Deserialize, Debug, Default, Clone)]
+#[reflect(Resource)]
+#[serde(default)]
+pub struct SaveData {
+    /// The best score for each daily challenge, keyed by the number of days
+    /// since the unix epoch. An entry exists as soon as the challenge is started.
+    pub daily_bests: BTreeMap<u64, f32>,
+    /// Currency earned from runs, spent in the shop
+    pub credits: u32,
+    pub upgrades: Upgrades,
+    /// The hull chosen in the hangar, used for every run
+    pub hull: Hull,
+    pub camera: CameraSettings,
+    pub palette: PaletteTheme,
+    pub language: Language,
+    pub ui_scale: UiScaleSetting,
+    pub particle_quality: ParticleQuality,
+    /// Replaces the nova's white flash with a gentler fade and tones down bloom
+    pub reduce_flashing: bool,
+    /// Lets the autopilot steer the ship clear of threats the player is
+    /// about to hit
+    pub dodge_assist: bool,
+}
+
+impl SaveData {
+    fn load() -> Self {
+        let Some(contents) = storage::read(SAVE_KEY) else {
+            return Self::default();
+        };
+
+        ron::from_str(&contents).unwrap_or_else(|err| {
+            warn!("Unable to read save data, starting fresh: {err}");
+            Self::default()
+        })
+    }
+}
+
+fn write_save_data(save: Res<SaveData>) {
+    match ron::to_string(&*save) {
+        Ok(contents) => storage::write(SAVE_KEY, &contents),
+        Err(err) => warn!("Unable to serialise save data: {err}"),
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+mod storage {
+    use std::path::PathBuf;
+
+    use bevy::log::warn;
+
+    fn path(key: &str) -> PathBuf {
+        PathBuf::from(format!("{key}.ron"))
+    }
+
+    pub fn read(key: &str) -> Option<String> {
+        std::fs::read_to_string(path(key)).ok()
+    }
+
+    pub fn write(key: &str, contents: &str) {
+        if let Err(err) = std::fs::write(path(key), contents) {
+            warn!("Unable to write save data: {err}");
+        }
+    }
+}
+
+#[cfg(target_family = "wasm")]
+mod storage {
+    fn local_storage() -> Option<web_sys::Storage> {
+        web_sys::window()?.local_storage().ok()?
+    }
+
+    pub fn read(key: &str) -> Option<String> {
+        local_storage()?.get_item(key).ok()?
+    }
+
+    pub fn write(key: &str, contents: &str) {
+        if let Some(storage) = local_storage() {
+            let _ = storage.set_item(key, contents);
+        }
+    }
+}
@@ -3,10 +3,15 @@
 
 use bevy::prelude::*;
 
-use crate::{asset_tracking::ResourceHandles, screens::Screen, theme::prelude::*};
+use crate::{asset_tracking::ResourceHandles, locale::UiText, screens::Screen, theme::prelude::*};
 
 pub(super) fn plugin(app: &mut App) {
-    app.add_systems(OnEnter(Screen::Loading), spawn_loading_screen);
+    // every run starts here, but there's only something to wait for the
+    // first time
+    app.add_systems(
+        OnEnter(Screen::Loading),
+        spawn_loading_screen.run_if(not(all_assets_loaded)),
+    );
 
     app.add_systems(
         Update,
@@ -18,7 +23,7 @@ fn spawn_loading_screen(mut commands: Commands) {
     commands.spawn((
         widget::ui_root("Loading Screen"),
         StateScoped(Screen::Loading),
-        children![widget::label("Loading...")],
+        children![widget::label(UiText::Key("loading"))],
     ));
 }
 
@@ -1,18 +1,18 @@
-//! The title screen that appears after the splash screen.
+//! The title screen that appears after the splash screen. It only lasts until
+//! the attract run starts behind the menu, see [`crate::attract`].
 
 use bevy::prelude::*;
 
 use crate::{menus::Menu, screens::Screen};
 
 pub(super) fn plugin(app: &mut App) {
-    app.add_systems(OnEnter(Screen::Title), open_main_menu);
-    app.add_systems(OnExit(Screen::Title), close_menu);
+    // the menu stays as it was when an attract run ends and the next begins
+    app.add_systems(
+        OnEnter(Screen::Title),
+        open_main_menu.run_if(in_state(Menu::None)),
+    );
 }
 
 fn open_main_menu(mut next_menu: ResMut<NextState<Menu>>) {
     next_menu.set(Menu::Main);
 }
-
-fn close_menu(mut next_menu: ResMut<NextState<Menu>>) {
-    next_menu.set(Menu::None);
-}
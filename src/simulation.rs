@@ -0,0 +1,227 @@
+//! Headless runs of the game flown by a scripted pilot, so balance changes can
+//! be judged without playtesting. The `simulate` binary runs these in bulk.
+
+use std::time::Duration;
+
+use bevy::{ecs::event::EventCursor, prelude::*, time::TimeUpdateStrategy};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+use crate::{
+    AppSystems, DeathReason, HeadlessPlugin,
+    asset_tracking::ResourceHandles,
+    autopilot::Autopilot,
+    input::{ThrustInput, record_thrust},
+    mode::GameMode,
+    player::{ItemPosition, Player},
+    rng::SeedOverride,
+    score::Score,
+    screens::Screen,
+    supernova::{Nova, NovaPhaseChanged},
+};
+
+/// How long the random pilot holds each decision for
+const RANDOM_THRUST_INTERVAL: f32 = 0.5;
+
+/// The most updates to wait for the assets to load before giving up
+const MAX_LOADING_UPDATES: u32 = 100_000;
+
+/// How a scripted pilot flies the ship, using only what a player can see
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Policy {
+    /// Never thrust, staying as close to the sun as possible
+    HugSun,
+    /// Thrust whenever the ship is closer to the sun than this
+    HoldRadius(f32),
+    /// Toss a coin every so often to decide whether to thrust
+    RandomThrust,
+    /// Hand the ship to the [`Autopilot`]
+    Autopilot,
+}
+
+impl Policy {
+    /// Parses `hug-sun`, `random-thrust`, `autopilot` or `hold-radius`, with
+    /// an optional radius such as `hold-radius=50`
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.split_once('=') {
+            Some(("hold-radius", radius)) => radius.parse().ok().map(Policy::HoldRadius),
+            Some(_) => None,
+            None => match name {
+                "hug-sun" => Some(Policy::HugSun),
+                "hold-radius" => Some(Policy::HoldRadius(50.0)),
+                "random-thrust" => Some(Policy::RandomThrust),
+                "autopilot" => Some(Policy::Autopilot),
+                _ => None,
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for Policy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Policy::HugSun => write!(f, "hug-sun"),
+            Policy::HoldRadius(radius) => write!(f, "hold-radius={radius}"),
+            Policy::RandomThrust => write!(f, "random-thrust"),
+            Policy::Autopilot => write!(f, "autopilot"),
+        }
+    }
+}
+
+/// The outcome of a simulated run
+#[derive(Debug, Clone)]
+pub struct RunSummary {
+    pub seed: u64,
+    pub score: f32,
+    /// Seconds of game time before the run ended
+    pub survival_time: f32,
+    /// The locale key for why the run ended, or `None` if it was still going
+    /// at the time limit
+    pub death_reason: Option<&'static str>,
+    /// The novas the ship made it through to the next star
+    pub novas_survived: u32,
+}
+
+/// The headless game, reused for one run after another
+pub struct Simulation {
+    app: App,
+    timestep: Duration,
+}
+
+impl Simulation {
+    /// Builds the headless game and waits for its assets to load. Each update
+    /// advances the game by `timestep`, however long it takes to compute.
+    pub fn new(timestep: Duration) -> Self {
+        let mut app = headless_app(timestep);
+        app.add_systems(
+            Update,
+            fly_ship
+                .in_set(AppSystems::RecordInput)
+                .after(record_thrust)
+                .run_if(in_state(Screen::Gameplay).and(resource_exists::<Pilot>)),
+        );
+        Self { app, timestep }
+    }
+
+    /// Plays one endless run, ending it early after `max_time` seconds
+    pub fn run(&mut self, seed: u64, policy: Policy, max_time: f32) -> RunSummary {
+        let world = self.app.world_mut();
+        world.insert_resource(GameMode::Endless);
+        world.insert_resource(SeedOverride(seed));
+        if policy == Policy::Autopilot {
+            world.insert_resource(Autopilot);
+        } else {
+            world.insert_resource(Pilot {
+                policy,
+                rng: StdRng::seed_from_u64(seed),
+                decision: false,
+                decided_at: f32::NEG_INFINITY,
+            });
+        }
+        world
+            .resource_mut::<NextState<Screen>>()
+            .set(Screen::Gameplay);
+        self.app.update();
+
+        let mut novas = self
+            .app
+            .world()
+            .resource::<Events<NovaPhaseChanged>>()
+            .get_cursor_current();
+        let mut novas_survived = 0;
+        let mut survival_time = 0.0;
+
+        let death_reason = loop {
+            self.app.update();
+            survival_time += self.timestep.as_secs_f32();
+            novas_survived += count_survived_novas(&mut novas, self.app.world());
+
+            let world = self.app.world_mut();
+            if *world.resource::<State<Screen>>() == Screen::GameOver {
+                break Some(world.resource::<DeathReason>().0);
+            }
+            if survival_time >= max_time {
+                break None;
+            }
+        };
+
+        let score = self.app.world().resource::<Score>().score;
+
+        // leave the run so the next one starts fresh. Not via the title
+        // screen, which would start an attract run.
+        let world = self.app.world_mut();
+        world.remove_resource::<Pilot>();
+        world.remove_resource::<Autopilot>();
+        world
+            .resource_mut::<NextState<Screen>>()
+            .set(Screen::GameOver);
+        self.app.update();
+
+        RunSummary {
+            seed,
+            score,
+            survival_time,
+            death_reason,
+            novas_survived,
+        }
+    }
+}
+
+/// Builds the headless game, still on the splash screen, and updates it until
+/// its assets have loaded. From then on each update advances the game by
+/// `timestep`.
+pub(crate) fn headless_app(timestep: Duration) -> App {
+    let mut app = App::new();
+    app.add_plugins(HeadlessPlugin);
+    // hold time still while loading, so the splash screen waits for the
+    // assets the title screen needs
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::ZERO));
+    app.finish();
+    app.cleanup();
+
+    for _ in 0..MAX_LOADING_UPDATES {
+        app.update();
+        if app.world().resource::<ResourceHandles>().is_all_done() {
+            app.insert_resource(TimeUpdateStrategy::ManualDuration(timestep));
+            return app;
+        }
+    }
+    panic!("the assets didn't load after {MAX_LOADING_UPDATES} updates");
+}
+
+fn count_survived_novas(cursor: &mut EventCursor<NovaPhaseChanged>, world: &World) -> u32 {
+    let events = world.resource::<Events<NovaPhaseChanged>>();
+    cursor
+        .read(events)
+        .filter(|changed| changed.from == Nova::During)
+        .count() as u32
+}
+
+#[derive(Resource)]
+struct Pilot {
+    policy: Policy,
+    rng: StdRng,
+    /// The last decision of the random pilot, and when it was made
+    decision: bool,
+    decided_at: f32,
+}
+
+fn fly_ship(
+    time: Res<Time>,
+    mut pilot: ResMut<Pilot>,
+    mut thrust: ResMut<ThrustInput>,
+    position: Single<&ItemPosition, With<Player>>,
+) {
+    thrust.0 = match pilot.policy {
+        Policy::HugSun => false,
+        Policy::HoldRadius(radius) => position.radius < radius,
+        Policy::RandomThrust => {
+            let now = time.elapsed_secs();
+            if now - pilot.decided_at >= RANDOM_THRUST_INTERVAL {
+                pilot.decision = pilot.rng.r#gen();
+                pilot.decided_at = now;
+            }
+            pilot.decision
+        }
+        Policy::Autopilot => return,
+    };
+}
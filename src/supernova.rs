@@ -1,246 +1,496 @@
-use bevy::{
-    color::palettes::css::{DARK_CYAN, WHITE},
-    prelude::*,
-};
-use bevy_seedling::sample::SamplePlayer;
-
-#[cfg(debug_assertions)]
-pub use bevy::dev_tools::states::log_transitions;
-
-use crate::{
-    MusicAssets, PlayerAssets,
-    consts::{INNER_SUN_COLOUR, SPLASH_BACKGROUND_COLOR, SUN_COLOUR},
-    materials::{StarfieldMaterial, SunMaterial},
-    player::Player,
-    score::Score,
-    screens::Screen,
-    sun::Sun,
-};
-
-const IDLE_PHASE: f32 = 30.0;
-const BUILD_PHASE: f32 = 6.0;
-const DURING_PHASE: f32 = 17.0;
-const AFTER_PHASE: f32 = 2.0;
-
-const BLOOMED_WHITE: Color = Color::srgba(3.0, 3.0, 3.0, 0.0);
-
-pub(super) fn plugin(app: &mut App) {
-    app.add_sub_state::<Nova>();
-    #[cfg(debug_assertions)]
-    app.add_systems(Update, log_transitions::<Nova>);
-    app.add_systems(
-        Update,
-        tick_nova_timer.run_if(resource_exists::<NovaTimer>.and(state_exists::<Nova>)),
-    );
-    app.add_systems(OnExit(Screen::Gameplay), destroy_timer);
-
-    app.add_systems(OnEnter(Nova::Idle), on_start_idle);
-    app.add_systems(OnExit(Nova::Idle), on_finish_idle);
-
-    app.add_systems(OnEnter(Nova::BuildingUp), on_start_buildup);
-    app.add_systems(Update, during_buildup.run_if(in_state(Nova::BuildingUp)));
-    app.add_systems(OnExit(Nova::BuildingUp), on_finish_buildup);
-
-    app.add_systems(OnEnter(Nova::During), on_start_during);
-    app.add_systems(OnExit(Nova::During), on_finish_during);
-
-    app.add_systems(OnEnter(Nova::After), on_start_after);
-    app.add_systems(Update, during_after.run_if(in_state(Nova::After)));
-    app.add_systems(OnExit(Nova::After), on_finish_after);
-}
-
-#[derive(SubStates, Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
-#[source(Screen = Screen::Gameplay)]
-#[states(scoped_entities)]
-pub enum Nova {
-    /// The default state where we are skipping the star
-    #[default]
-    Idle,
-    /// When the nova is started but we haven't yet left the star
-    BuildingUp,
-    /// While the nova is happening and we're travelling to the next star
-    During,
-    /// While we're arriving at the next star, before we start skimming
-    After,
-}
-
-impl Nova {
-    fn next_state(&self) -> Self {
-        match self {
-            Nova::Idle => Nova::BuildingUp,
-            Nova::BuildingUp => Nova::During,
-            Nova::During => Nova::After,
-            Nova::After => Nova::Idle,
-        }
-    }
-}
-
-/* SHARED */
-
-fn tick_nova_timer(
-    time: Res<Time>,
-    mut timer: ResMut<NovaTimer>,
-    state: Res<State<Nova>>,
-    mut next_nova_state: ResMut<NextState<Nova>>,
-) {
-    timer.0.tick(time.delta());
-    if timer.0.just_finished() {
-        info!("Nova timer pinged!");
-        next_nova_state.set(state.next_state());
-    }
-}
-
-fn destroy_timer(mut commands: Commands) {
-    commands.remove_resource::<NovaTimer>();
-}
-
-/* NOVA IDLE */
-
-#[derive(Resource, Reflect)]
-#[reflect(Resource)]
-pub struct NovaTimer(pub Timer);
-
-fn on_start_idle(mut commands: Commands) {
-    commands.insert_resource(NovaTimer(Timer::from_seconds(IDLE_PHASE, TimerMode::Once)));
-}
-
-fn on_finish_idle() {}
-
-/* NOVA BUILDING UP */
-
-fn on_start_buildup(
-    mut commands: Commands,
-    player_assets: Res<PlayerAssets>,
-    music_assets: Res<MusicAssets>,
-) {
-    commands.spawn(SamplePlayer::new(player_assets.nova_alert.clone()));
-    commands.insert_resource(NovaTimer(Timer::from_seconds(BUILD_PHASE, TimerMode::Once)));
-    commands.spawn((
-        StateScoped(Screen::Gameplay),
-        SamplePlayer::new(music_assets.supernova.clone()),
-    ));
-
-    commands.spawn((
-        StateScoped(Nova::BuildingUp),
-        Node {
-            position_type: PositionType::Absolute,
-            top: Val::ZERO,
-            left: Val::ZERO,
-            width: Val::Percent(100.0),
-            height: Val::Percent(100.0),
-            justify_content: JustifyContent::Center,
-            align_items: AlignItems::Center,
-            ..default()
-        },
-        children![(
-            Node {
-                position_type: PositionType::Relative,
-                top: Val::Px(45.0),
-                ..default()
-            },
-            Text::new("!!NOVA ALERT!!\nENGAGING WARP DRIVE\nAVOID BLACK HOLES"),
-            TextLayout {
-                justify: JustifyText::Center,
-                ..default()
-            },
-            TextFont {
-                font_size: 12.0,
-                ..default()
-            },
-            TextColor(WHITE.into()),
-        )],
-    ));
-}
-
-fn during_buildup(
-    timer: Res<NovaTimer>,
-    mut starfield_mats: ResMut<Assets<StarfieldMaterial>>,
-    mut player_mats: ResMut<Assets<ColorMaterial>>,
-    mut sun_mats: ResMut<Assets<SunMaterial>>,
-    starfield: Single<&mut MeshMaterial2d<StarfieldMaterial>>,
-    player: Single<&mut MeshMaterial2d<ColorMaterial>, With<Player>>,
-    sun: Single<(&mut Transform, &MeshMaterial2d<SunMaterial>)>,
-) {
-    let counter = 1.0 - 2.0 * timer.0.fraction_remaining();
-
-    let starfield_col = SPLASH_BACKGROUND_COLOR.mix(&BLOOMED_WHITE, counter);
-    let player_col = BLOOMED_WHITE.mix(&Color::Srgba(DARK_CYAN), counter);
-    let sun_outer = SUN_COLOUR.mix(&BLOOMED_WHITE, counter);
-    let sun_inner = INNER_SUN_COLOUR.mix(&BLOOMED_WHITE, counter);
-
-    if let Some(material) = starfield_mats.get_mut(&starfield.0) {
-        material.background = starfield_col.into();
-    }
-
-    if let Some(material) = player_mats.get_mut(&player.0) {
-        material.color = player_col;
-    }
-
-    let (mut sun_tx, sun_mat) = sun.into_inner();
-    if let Some(material) = sun_mats.get_mut(sun_mat) {
-        material.inner_color = sun_inner.to_srgba().to_vec4();
-        material.color = sun_outer.to_srgba().to_vec4();
-    }
-    sun_tx.scale = Vec3::splat(counter);
-}
-
-fn on_finish_buildup(mut sun_vis: Single<&mut Visibility, With<Sun>>) {
-    **sun_vis = Visibility::Hidden;
-}
-
-/* NOVA DURING */
-
-fn on_start_during(mut commands: Commands) {
-    commands.insert_resource(NovaTimer(Timer::from_seconds(
-        DURING_PHASE,
-        TimerMode::Once,
-    )));
-}
-
-fn on_finish_during(mut sun_vis: Single<&mut Visibility, With<Sun>>) {
-    **sun_vis = Visibility::Visible;
-}
-
-/* NOVA POST */
-
-fn on_start_after(mut commands: Commands, mut score: ResMut<Score>) {
-    commands.insert_resource(NovaTimer(Timer::from_seconds(AFTER_PHASE, TimerMode::Once)));
-    score.multiplier += 5;
-}
-
-fn during_after(
-    timer: Res<NovaTimer>,
-    mut starfield_mats: ResMut<Assets<StarfieldMaterial>>,
-    mut player_mats: ResMut<Assets<ColorMaterial>>,
-    mut sun_mats: ResMut<Assets<SunMaterial>>,
-    starfield: Single<&mut MeshMaterial2d<StarfieldMaterial>>,
-    player: Single<&mut MeshMaterial2d<ColorMaterial>, With<Player>>,
-    sun: Single<(&mut Transform, &MeshMaterial2d<SunMaterial>)>,
-) {
-    let counter = 1.0 - timer.0.fraction_remaining();
-
-    let starfield_col = BLOOMED_WHITE.mix(&SPLASH_BACKGROUND_COLOR, counter);
-    let player_col = Color::Srgba(DARK_CYAN).mix(&BLOOMED_WHITE, counter);
-    let sun_outer = BLOOMED_WHITE.mix(&SUN_COLOUR, counter);
-
-    let sun_inner = BLOOMED_WHITE.mix(&INNER_SUN_COLOUR, counter);
-
-    if let Some(material) = starfield_mats.get_mut(&starfield.0) {
-        material.background = starfield_col.into();
-    }
-
-    if let Some(material) = player_mats.get_mut(&player.0) {
-        material.color = player_col;
-    }
-
-    let (mut sun_tx, sun_mat) = sun.into_inner();
-    if let Some(material) = sun_mats.get_mut(sun_mat) {
-        material.inner_color = sun_inner.to_srgba().to_vec4();
-        material.color = sun_outer.to_srgba().to_vec4();
-    }
-    sun_tx.scale = Vec3::splat(counter);
-}
-
-fn on_finish_after() {
-    //
-}
+use bevy::{color::palettes::css::WHITE, ecs::system::SystemParam, prelude::*};
+use bevy_seedling::sample::SamplePlayer;
+use serde::Serialize;
+
+#[cfg(debug_assertions)]
+pub use bevy::dev_tools::states::log_transitions;
+
+use crate::{
+    MusicAssets, PlayerAssets,
+    consts::SPLASH_BACKGROUND_COLOR,
+    locale::{Locale, UiText},
+    materials::{StarfieldMaterial, SunMaterial},
+    player::Player,
+    save::SaveData,
+    score::Score,
+    screens::Screen,
+    sun::Sun,
+    theme::{layout, palette::Palette},
+    tutorial::tutorial_allows_nova,
+};
+
+const IDLE_PHASE: f32 = 30.0;
+const BUILD_PHASE: f32 = 6.0;
+const DURING_PHASE: f32 = 17.0;
+const AFTER_PHASE: f32 = 2.0;
+
+/// How long before the nova the countdown starts pulsing
+const NOVA_WARNING_TIME: f32 = 5.0;
+const COUNTDOWN_FONT_SIZE: f32 = 28.0;
+
+/// The most the luminance of the nova colours can change each second with
+/// reduced flashing turned on
+const MAX_LUMINANCE_CHANGE: f32 = 0.8;
+/// How far the background is tinted at the peak of the nova instead of
+/// flashing, with reduced flashing turned on
+const REDUCED_FLASH_TINT: f32 = 0.3;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_sub_state::<Nova>();
+    app.register_type::<NovaTimer>();
+    app.register_type::<NovaPhaseLabel>();
+    app.register_type::<NovaCountdown>();
+    app.register_type::<NovaColours>();
+    app.register_type::<SupernovaMusic>();
+
+    app.add_event::<NovaPhaseChanged>();
+    #[cfg(debug_assertions)]
+    app.add_systems(Update, log_transitions::<Nova>);
+    app.add_systems(
+        Update,
+        tick_nova_timer.run_if(
+            resource_exists::<NovaTimer>
+                .and(state_exists::<Nova>)
+                .and(tutorial_allows_nova),
+        ),
+    );
+    app.add_systems(OnExit(Screen::Gameplay), destroy_timer);
+
+    app.add_systems(OnEnter(Screen::Gameplay), spawn_nova_hud);
+    app.add_systems(
+        Update,
+        update_nova_hud.run_if(resource_exists::<NovaTimer>.and(state_exists::<Nova>)),
+    );
+
+    app.add_systems(OnEnter(Screen::Gameplay), reset_nova_colours);
+    app.add_systems(
+        Update,
+        settle_nova_colours.run_if(in_state(Nova::Idle).and(resource_exists::<NovaColours>)),
+    );
+
+    app.add_systems(OnEnter(Nova::Idle), on_start_idle);
+    app.add_systems(OnExit(Nova::Idle), on_finish_idle);
+
+    app.add_systems(OnEnter(Nova::BuildingUp), on_start_buildup);
+    app.add_systems(Update, during_buildup.run_if(in_state(Nova::BuildingUp)));
+    app.add_systems(OnExit(Nova::BuildingUp), on_finish_buildup);
+
+    app.add_systems(OnEnter(Nova::During), on_start_during);
+    app.add_systems(OnExit(Nova::During), on_finish_during);
+
+    app.add_systems(OnEnter(Nova::After), on_start_after);
+    app.add_systems(Update, during_after.run_if(in_state(Nova::After)));
+    app.add_systems(OnExit(Nova::After), on_finish_after);
+}
+
+#[derive(SubStates, Serialize, Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+#[source(Screen = Screen::Gameplay)]
+#[states(scoped_entities)]
+pub enum Nova {
+    /// The default state where we are skipping the star
+    #[default]
+    Idle,
+    /// When the nova is started but we haven't yet left the star
+    BuildingUp,
+    /// While the nova is happening and we're travelling to the next star
+    During,
+    /// While we're arriving at the next star, before we start skimming
+    After,
+}
+
+impl Nova {
+    /// The locale key for the name of the phase shown in the HUD
+    fn label_key(&self) -> &'static str {
+        match self {
+            Nova::Idle => "nova.idle",
+            Nova::BuildingUp => "nova.building_up",
+            Nova::During => "nova.during",
+            Nova::After => "nova.after",
+        }
+    }
+
+    fn next_state(&self) -> Self {
+        match self {
+            Nova::Idle => Nova::BuildingUp,
+            Nova::BuildingUp => Nova::During,
+            Nova::During => Nova::After,
+            Nova::After => Nova::Idle,
+        }
+    }
+}
+
+/* SHARED */
+
+/// Sent when the nova timer moves on to the next phase
+#[derive(Event, Serialize, Debug, Clone, Copy)]
+pub struct NovaPhaseChanged {
+    pub from: Nova,
+    pub to: Nova,
+}
+
+fn tick_nova_timer(
+    time: Res<Time>,
+    mut timer: ResMut<NovaTimer>,
+    state: Res<State<Nova>>,
+    mut next_nova_state: ResMut<NextState<Nova>>,
+    mut changed: EventWriter<NovaPhaseChanged>,
+) {
+    timer.0.tick(time.delta());
+    if timer.0.just_finished() {
+        info!("Nova timer pinged!");
+        let to = state.next_state();
+        next_nova_state.set(to);
+        changed.write(NovaPhaseChanged { from: **state, to });
+    }
+}
+
+fn destroy_timer(mut commands: Commands) {
+    commands.remove_resource::<NovaTimer>();
+}
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct NovaPhaseLabel;
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct NovaCountdown;
+
+fn spawn_nova_hud(mut commands: Commands) {
+    commands.spawn((
+        Name::new("Nova HUD"),
+        StateScoped(Screen::Gameplay),
+        // drops below the HUD on the right once it no longer fits beside it
+        layout::responsive(nova_hud_node(10.0), nova_hud_node(150.0)),
+        Pickable::IGNORE,
+        children![
+            (
+                Text::new(""),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(WHITE.into()),
+                NovaPhaseLabel,
+            ),
+            (
+                Text::new(""),
+                TextFont {
+                    font_size: COUNTDOWN_FONT_SIZE,
+                    ..default()
+                },
+                TextColor(WHITE.into()),
+                NovaCountdown,
+            ),
+        ],
+    ));
+}
+
+fn nova_hud_node(top: f32) -> Node {
+    Node {
+        position_type: PositionType::Absolute,
+        top: Val::Px(top),
+        left: Val::ZERO,
+        width: Val::Percent(100.0),
+        flex_direction: FlexDirection::Column,
+        align_items: AlignItems::Center,
+        ..default()
+    }
+}
+
+fn update_nova_hud(
+    time: Res<Time>,
+    timer: Res<NovaTimer>,
+    save: Res<SaveData>,
+    locale: Res<Locale>,
+    palette: Res<Palette>,
+    state: Res<State<Nova>>,
+    mut phase_label: Single<&mut Text, (With<NovaPhaseLabel>, Without<NovaCountdown>)>,
+    countdown: Single<(&mut Text, &mut TextFont, &mut TextColor), With<NovaCountdown>>,
+) {
+    let (mut text, mut font, mut color) = countdown.into_inner();
+    let remaining = timer.0.remaining_secs();
+
+    phase_label.0 = locale.get(state.label_key()).into();
+    text.0 = format!("{:.0}", remaining.ceil());
+
+    // pulse in the last few seconds before the nova
+    if **state == Nova::Idle && remaining <= NOVA_WARNING_TIME {
+        let pulse = 0.5 + 0.5 * (time.elapsed_secs() * std::f32::consts::TAU * 2.0).sin();
+        font.font_size = COUNTDOWN_FONT_SIZE * (1.0 + 0.25 * pulse);
+        color.0 = if save.reduce_flashing {
+            palette.danger
+        } else {
+            Color::from(WHITE).mix(&palette.danger, pulse)
+        };
+    } else {
+        font.font_size = COUNTDOWN_FONT_SIZE;
+        color.0 = WHITE.into();
+    }
+}
+
+/* NOVA IDLE */
+
+#[derive(Resource, Reflect)]
+#[reflect(Resource)]
+pub struct NovaTimer(pub Timer);
+
+fn on_start_idle(mut commands: Commands) {
+    commands.insert_resource(NovaTimer(Timer::from_seconds(IDLE_PHASE, TimerMode::Once)));
+}
+
+fn on_finish_idle() {}
+
+/* NOVA BUILDING UP */
+
+/// The music that plays from the build up until the nova is over
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct SupernovaMusic;
+
+fn on_start_buildup(
+    mut commands: Commands,
+    player_assets: Res<PlayerAssets>,
+    music_assets: Res<MusicAssets>,
+) {
+    commands.spawn(SamplePlayer::new(player_assets.nova_alert.clone()));
+    commands.insert_resource(NovaTimer(Timer::from_seconds(BUILD_PHASE, TimerMode::Once)));
+    commands.spawn((
+        SupernovaMusic,
+        StateScoped(Screen::Gameplay),
+        SamplePlayer::new(music_assets.supernova.clone()),
+    ));
+
+    commands.spawn((
+        StateScoped(Nova::BuildingUp),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::ZERO,
+            left: Val::ZERO,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        children![(
+            Node {
+                position_type: PositionType::Relative,
+                top: Val::Px(45.0),
+                ..default()
+            },
+            Text::default(),
+            UiText::Key("hud.nova_alert"),
+            TextLayout {
+                justify: JustifyText::Center,
+                ..default()
+            },
+            TextFont {
+                font_size: 12.0,
+                ..default()
+            },
+            TextColor(WHITE.into()),
+        )],
+    ));
+}
+
+fn during_buildup(
+    time: Res<Time>,
+    timer: Res<NovaTimer>,
+    save: Res<SaveData>,
+    palette: Res<Palette>,
+    mut colours: ResMut<NovaColours>,
+    mut materials: NovaMaterials,
+    mut sun_tx: Single<&mut Transform, With<Sun>>,
+) {
+    let counter = 1.0 - 2.0 * timer.0.fraction_remaining();
+
+    let target =
+        NovaColours::resting(&save, &palette).mix(&NovaColours::flash(&save, &palette), counter);
+    colours.update(target, save.reduce_flashing, time.delta_secs());
+    materials.apply(&colours);
+
+    sun_tx.scale = Vec3::splat(counter);
+}
+
+fn on_finish_buildup(mut sun_vis: Single<&mut Visibility, With<Sun>>) {
+    **sun_vis = Visibility::Hidden;
+}
+
+/* NOVA DURING */
+
+fn on_start_during(mut commands: Commands) {
+    commands.insert_resource(NovaTimer(Timer::from_seconds(
+        DURING_PHASE,
+        TimerMode::Once,
+    )));
+}
+
+fn on_finish_during(mut sun_vis: Single<&mut Visibility, With<Sun>>) {
+    **sun_vis = Visibility::Visible;
+}
+
+/* NOVA POST */
+
+fn on_start_after(mut commands: Commands, mut score: ResMut<Score>) {
+    commands.insert_resource(NovaTimer(Timer::from_seconds(AFTER_PHASE, TimerMode::Once)));
+    score.multiplier += 5;
+}
+
+fn during_after(
+    time: Res<Time>,
+    timer: Res<NovaTimer>,
+    save: Res<SaveData>,
+    palette: Res<Palette>,
+    mut colours: ResMut<NovaColours>,
+    mut materials: NovaMaterials,
+    mut sun_tx: Single<&mut Transform, With<Sun>>,
+) {
+    let counter = 1.0 - timer.0.fraction_remaining();
+
+    let target =
+        NovaColours::flash(&save, &palette).mix(&NovaColours::resting(&save, &palette), counter);
+    colours.update(target, save.reduce_flashing, time.delta_secs());
+    materials.apply(&colours);
+
+    sun_tx.scale = Vec3::splat(counter);
+}
+
+/// Stops the music once the nova is over, so each nova starts it afresh
+fn on_finish_after(mut commands: Commands, music: Query<Entity, With<SupernovaMusic>>) {
+    for entity in &music {
+        commands.entity(entity).despawn();
+    }
+}
+
+/* NOVA COLOURS */
+
+/// The colours the nova fades the starfield, player and sun between
+#[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Resource)]
+struct NovaColours {
+    starfield: Color,
+    player: Color,
+    sun_inner: Color,
+    sun_outer: Color,
+}
+
+impl NovaColours {
+    /// The colours outside of a nova
+    fn resting(save: &SaveData, palette: &Palette) -> Self {
+        Self {
+            starfield: SPLASH_BACKGROUND_COLOR,
+            player: save.hull.color(),
+            sun_inner: palette.inner_sun,
+            sun_outer: palette.sun,
+        }
+    }
+
+    /// The colours at the peak of the nova. With reduced flashing the sun
+    /// keeps its colour and the background only takes on a tint, rather than
+    /// everything blooming to white.
+    fn flash(save: &SaveData, palette: &Palette) -> Self {
+        if save.reduce_flashing {
+            Self {
+                starfield: SPLASH_BACKGROUND_COLOR.mix(&palette.warp_tint, REDUCED_FLASH_TINT),
+                player: palette.warp_tint,
+                sun_inner: palette.inner_sun,
+                sun_outer: palette.sun,
+            }
+        } else {
+            Self {
+                starfield: palette.nova_flash,
+                player: palette.warp_tint,
+                sun_inner: palette.nova_flash,
+                sun_outer: palette.nova_flash,
+            }
+        }
+    }
+
+    fn mix(&self, other: &Self, t: f32) -> Self {
+        Self {
+            starfield: self.starfield.mix(&other.starfield, t),
+            player: self.player.mix(&other.player, t),
+            sun_inner: self.sun_inner.mix(&other.sun_inner, t),
+            sun_outer: self.sun_outer.mix(&other.sun_outer, t),
+        }
+    }
+
+    /// Moves towards the target colours, limiting how fast the luminance
+    /// changes when flashing is reduced
+    fn update(&mut self, target: Self, reduce_flashing: bool, delta: f32) {
+        if !reduce_flashing {
+            *self = target;
+            return;
+        }
+
+        let max_change = MAX_LUMINANCE_CHANGE * delta;
+        *self = Self {
+            starfield: limit_luminance_change(self.starfield, target.starfield, max_change),
+            player: limit_luminance_change(self.player, target.player, max_change),
+            sun_inner: limit_luminance_change(self.sun_inner, target.sun_inner, max_change),
+            sun_outer: limit_luminance_change(self.sun_outer, target.sun_outer, max_change),
+        };
+    }
+}
+
+fn limit_luminance_change(current: Color, target: Color, max_change: f32) -> Color {
+    let from = LinearRgba::from(current);
+    let to = LinearRgba::from(target);
+
+    let change = (to.luminance() - from.luminance()).abs();
+    if change <= max_change {
+        target
+    } else {
+        from.mix(&to, max_change / change).into()
+    }
+}
+
+#[derive(SystemParam)]
+struct NovaMaterials<'w> {
+    starfield_mats: ResMut<'w, Assets<StarfieldMaterial>>,
+    player_mats: ResMut<'w, Assets<ColorMaterial>>,
+    sun_mats: ResMut<'w, Assets<SunMaterial>>,
+    starfield: Single<'w, &'static MeshMaterial2d<StarfieldMaterial>>,
+    player: Single<'w, &'static MeshMaterial2d<ColorMaterial>, With<Player>>,
+    sun: Single<'w, &'static MeshMaterial2d<SunMaterial>, With<Sun>>,
+}
+
+impl NovaMaterials<'_> {
+    fn apply(&mut self, colours: &NovaColours) {
+        if let Some(material) = self.starfield_mats.get_mut(&self.starfield.0) {
+            material.background = colours.starfield.into();
+        }
+
+        if let Some(material) = self.player_mats.get_mut(&self.player.0) {
+            material.color = colours.player;
+        }
+
+        if let Some(material) = self.sun_mats.get_mut(&self.sun.0) {
+            material.set_colors(colours.sun_inner, colours.sun_outer);
+        }
+    }
+}
+
+fn reset_nova_colours(mut commands: Commands, save: Res<SaveData>, palette: Res<Palette>) {
+    commands.insert_resource(NovaColours::resting(&save, &palette));
+}
+
+/// Finishes fading back to the resting colours, which can outlast the arrival
+/// phase when flashing is reduced
+fn settle_nova_colours(
+    time: Res<Time>,
+    save: Res<SaveData>,
+    palette: Res<Palette>,
+    mut colours: ResMut<NovaColours>,
+    mut materials: NovaMaterials,
+) {
+    let target = NovaColours::resting(&save, &palette);
+    if *colours == target {
+        return;
+    }
+
+    colours.update(target, save.reduce_flashing, time.delta_secs());
+    materials.apply(&colours);
+}
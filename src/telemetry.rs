@@ -0,0 +1,169 @@
+//! Records the gameplay events to a JSON lines file for balance analysis.
+//!
+//! Recording is opt-in: set `NOVA_LOOPER_TELEMETRY` to the path of a file and
+//! one line is appended for each event, timed from the start of the run. Web
+//! builds never record.
+
+use bevy::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    #[cfg(not(target_family = "wasm"))]
+    recorder::plugin(app);
+    #[cfg(target_family = "wasm")]
+    let _ = app;
+}
+
+#[cfg(not(target_family = "wasm"))]
+mod recorder {
+    use std::{
+        fs::{File, OpenOptions},
+        io::{BufWriter, Write},
+    };
+
+    use bevy::prelude::*;
+    use serde::Serialize;
+    use web_time::{SystemTime, UNIX_EPOCH};
+
+    use crate::{
+        mode::GameMode,
+        obstacle::{PlayerCollision, PlayerDied},
+        player::ShieldAlarmChanged,
+        rng::{self, RunRng},
+        score::MultiplierIncreased,
+        screens::Screen,
+        supernova::NovaPhaseChanged,
+    };
+
+    const PATH_VARIABLE: &str = "NOVA_LOOPER_TELEMETRY";
+
+    pub fn plugin(app: &mut App) {
+        let Ok(path) = std::env::var(PATH_VARIABLE) else {
+            return;
+        };
+
+        let file = match OpenOptions::new().create(true).append(true).open(&path) {
+            Ok(file) => file,
+            Err(err) => {
+                warn!("Unable to open the telemetry file {path}: {err}");
+                return;
+            }
+        };
+        info!("Recording telemetry to {path}");
+
+        app.insert_resource(Recorder {
+            writer: BufWriter::new(file),
+            run_started: 0.0,
+        });
+        // attract runs only play behind the menu, so they aren't balance data
+        app.add_systems(
+            OnEnter(Screen::Gameplay),
+            record_run_start
+                .after(rng::seed_run_rng)
+                .run_if(not(resource_equals(GameMode::Attract))),
+        );
+        app.add_systems(Last, record_events);
+    }
+
+    #[derive(Resource)]
+    struct Recorder {
+        writer: BufWriter<File>,
+        /// The elapsed time when the current run started
+        run_started: f32,
+    }
+
+    impl Recorder {
+        fn write(&mut self, time: &Time, event: Event) {
+            let entry = Entry {
+                time: time.elapsed_secs() - self.run_started,
+                event,
+            };
+            let result = serde_json::to_writer(&mut self.writer, &entry)
+                .map_err(std::io::Error::from)
+                .and_then(|_| self.writer.write_all(b"\n"));
+            if let Err(err) = result {
+                warn!("Unable to write telemetry: {err}");
+            }
+        }
+    }
+
+    /// One line of the file
+    #[derive(Serialize)]
+    struct Entry {
+        /// Seconds since the run started
+        time: f32,
+        #[serde(flatten)]
+        event: Event,
+    }
+
+    #[derive(Serialize)]
+    #[serde(tag = "event", rename_all = "snake_case")]
+    enum Event {
+        RunStarted {
+            mode: GameMode,
+            seed: u64,
+            /// Seconds since the unix epoch, to tell the runs apart
+            started_at: u64,
+        },
+        Collision {
+            with: PlayerCollision,
+        },
+        MultiplierIncreased(MultiplierIncreased),
+        NovaPhaseChanged(NovaPhaseChanged),
+        ShieldAlarmChanged(ShieldAlarmChanged),
+        Died(PlayerDied),
+    }
+
+    fn record_run_start(
+        time: Res<Time>,
+        mode: Res<GameMode>,
+        rng: Res<RunRng>,
+        mut recorder: ResMut<Recorder>,
+    ) {
+        recorder.run_started = time.elapsed_secs();
+
+        let started_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|since| since.as_secs())
+            .unwrap_or_default();
+        recorder.write(
+            &time,
+            Event::RunStarted {
+                mode: *mode,
+                seed: rng.seed,
+                started_at,
+            },
+        );
+    }
+
+    fn record_events(
+        time: Res<Time>,
+        mut recorder: ResMut<Recorder>,
+        mut collisions: EventReader<PlayerCollision>,
+        mut multipliers: EventReader<MultiplierIncreased>,
+        mut novas: EventReader<NovaPhaseChanged>,
+        mut alarms: EventReader<ShieldAlarmChanged>,
+        mut deaths: EventReader<PlayerDied>,
+        mode: Res<GameMode>,
+    ) {
+        let events = collisions
+            .read()
+            .map(|&with| Event::Collision { with })
+            .chain(multipliers.read().copied().map(Event::MultiplierIncreased))
+            .chain(novas.read().copied().map(Event::NovaPhaseChanged))
+            .chain(alarms.read().copied().map(Event::ShieldAlarmChanged))
+            .chain(deaths.read().copied().map(Event::Died))
+            .collect::<Vec<_>>();
+
+        // read anyway, so an attract run's events aren't left for the next run
+        if events.is_empty() || *mode == GameMode::Attract {
+            return;
+        }
+
+        for event in events {
+            recorder.write(&time, event);
+        }
+        if let Err(err) = recorder.writer.flush() {
+            warn!("Unable to write telemetry: {err}");
+        }
+    }
+}
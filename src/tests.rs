@@ -0,0 +1,158 @@
+//! Tests that drive the whole game, headless, through its screens.
+
+mod daily;
+mod flows;
+mod gravity;
+#[cfg(debug_assertions)]
+mod leaks;
+
+use std::time::Duration;
+
+use bevy::{
+    ecs::{component::Mutable, query::QueryFilter},
+    prelude::*,
+};
+
+use crate::{
+    menus::{Menu, StartRun},
+    mode::GameMode,
+    player::Player,
+    screens::Screen,
+    simulation,
+    supernova::{Nova, NovaTimer},
+};
+
+/// The game time each update advances by
+const TIMESTEP: f32 = 1.0 / 60.0;
+
+/// The headless game, with helpers for moving it between states
+struct TestApp {
+    app: App,
+}
+
+impl TestApp {
+    /// Starts the game on the splash screen, with its assets loaded
+    fn new() -> Self {
+        Self {
+            app: simulation::headless_app(Duration::from_secs_f32(TIMESTEP)),
+        }
+    }
+
+    fn world(&self) -> &World {
+        self.app.world()
+    }
+
+    fn world_mut(&mut self) -> &mut World {
+        self.app.world_mut()
+    }
+
+    fn update(&mut self) {
+        self.app.update();
+    }
+
+    /// Updates until `done` returns true, failing the test if that takes
+    /// longer than `max_secs` of game time
+    fn run_until(&mut self, max_secs: f32, done: impl Fn(&World) -> bool) {
+        let mut elapsed = 0.0;
+        while !done(self.world()) {
+            assert!(
+                elapsed <= max_secs,
+                "still waiting after {max_secs} seconds, on {:?}",
+                self.screen(),
+            );
+            self.update();
+            elapsed += TIMESTEP;
+        }
+    }
+
+    fn screen(&self) -> Screen {
+        *self.world().resource::<State<Screen>>().get()
+    }
+
+    fn nova(&self) -> Option<Nova> {
+        self.world()
+            .get_resource::<State<Nova>>()
+            .map(|state| *state.get())
+    }
+
+    fn has<R: Resource>(&self) -> bool {
+        self.world().contains_resource::<R>()
+    }
+
+    /// Moves to `screen`, updating once for the transition and again for
+    /// any states it changes in turn, such as the menu
+    fn go_to(&mut self, screen: Screen) {
+        self.world_mut()
+            .resource_mut::<NextState<Screen>>()
+            .set(screen);
+        self.update();
+        self.update();
+        assert_eq!(self.screen(), screen);
+    }
+
+    /// Waits out the splash screen, or the end of a run, until the main menu
+    /// is open with an attract run playing behind it
+    fn wait_for_title(&mut self) {
+        self.run_until(5.0, |world| {
+            *world.resource::<State<Screen>>() == Screen::Gameplay
+                && *world.resource::<GameMode>() == GameMode::Attract
+                && *world.resource::<State<Menu>>() == Menu::Main
+        });
+    }
+
+    /// Starts a run from the title screen, the way the menus do
+    fn start_run(&mut self, mode: GameMode) {
+        self.start(StartRun { mode, daily: None });
+    }
+
+    fn start(&mut self, run: StartRun) {
+        self.wait_for_title();
+        run.apply(self.world_mut());
+        self.run_until(1.0, |world| {
+            *world.resource::<State<Screen>>() == Screen::Gameplay
+                && *world.resource::<GameMode>() == run.mode
+        });
+        // once more for the states that change in turn, such as the nova
+        self.update();
+    }
+
+    /// Leaves for the title screen, updating just once so that nothing from
+    /// the next attract run has been spawned yet
+    fn quit_to_title(&mut self) {
+        self.world_mut()
+            .resource_mut::<NextState<Screen>>()
+            .set(Screen::Title);
+        self.update();
+        assert_eq!(self.screen(), Screen::Title);
+    }
+
+    /// Runs the current nova phase's timer out, returning the phase the
+    /// nova moves on to
+    fn finish_nova_phase(&mut self) -> Nova {
+        let mut timer = self.world_mut().resource_mut::<NovaTimer>();
+        let almost_done = timer.0.duration().saturating_sub(Duration::from_millis(1));
+        timer.0.set_elapsed(almost_done);
+
+        // one update to finish the timer, and another for the transition
+        self.update();
+        self.update();
+        self.nova().expect("the nova only exists during gameplay")
+    }
+
+    fn count<F: QueryFilter>(&mut self) -> usize {
+        self.world_mut()
+            .query_filtered::<(), F>()
+            .iter(self.app.world())
+            .count()
+    }
+
+    /// Changes a component on the player's ship
+    fn edit_player<T: Component<Mutability = Mutable>>(&mut self, edit: impl FnOnce(&mut T)) {
+        let mut component = self
+            .world_mut()
+            .query_filtered::<&mut T, With<Player>>()
+            .single_mut(self.app.world_mut())
+            .expect("there should be exactly one player");
+        edit(&mut component);
+    }
+}
@@ -0,0 +1,88 @@
+use std::{collections::HashSet, f32::consts::PI};
+
+use bevy::prelude::*;
+
+use super::TestApp;
+use crate::{
+    autopilot::Autopilot,
+    daily::DailyChallenge,
+    menus::StartRun,
+    mode::GameMode,
+    obstacle::{BlackHole, Obstacle},
+    player::{ItemPosition, PlayerShield},
+    supernova::Nova,
+};
+
+/// Where everything was placed during a daily run: each obstacle's frame,
+/// angle and radius as it spawned, then each black hole's position
+#[derive(Debug, PartialEq)]
+struct Layout {
+    obstacles: Vec<(u32, f32, f32)>,
+    black_holes: Vec<Vec2>,
+}
+
+/// Plays the same day's challenge, flown by `fly` each frame, and records
+/// its layout
+fn play_daily(autopilot: bool, mut fly: impl FnMut(&mut TestApp)) -> Layout {
+    let mut game = TestApp::new();
+    game.start(StartRun {
+        mode: GameMode::Endless,
+        daily: Some(DailyChallenge { day: 20_000 }),
+    });
+    if autopilot {
+        game.world_mut().insert_resource(Autopilot);
+    }
+
+    let mut seen = HashSet::new();
+    let mut obstacles = Vec::new();
+    for frame in 0..300 {
+        fly(&mut game);
+        // keep the ship alive however badly it flies
+        game.edit_player(|shield: &mut PlayerShield| shield.current = shield.max);
+        game.update();
+
+        let world = game.world_mut();
+        let mut spawned: Vec<_> = world
+            .query_filtered::<(Entity, &ItemPosition), With<Obstacle>>()
+            .iter(world)
+            .filter(|(entity, _)| seen.insert(*entity))
+            .map(|(_, position)| (frame, position.theta, position.radius))
+            .collect();
+        spawned.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        obstacles.extend(spawned);
+    }
+    assert!(!obstacles.is_empty(), "no obstacles spawned");
+
+    assert_eq!(game.finish_nova_phase(), Nova::BuildingUp);
+    assert_eq!(game.finish_nova_phase(), Nova::During);
+    let world = game.world_mut();
+    let mut black_holes: Vec<_> = world
+        .query_filtered::<&Transform, With<BlackHole>>()
+        .iter(world)
+        .map(|transform| transform.translation.truncate())
+        .collect();
+    black_holes.sort_by(|a, b| a.to_array().partial_cmp(&b.to_array()).unwrap());
+    assert!(!black_holes.is_empty(), "no black holes spawned");
+
+    Layout {
+        obstacles,
+        black_holes,
+    }
+}
+
+#[test]
+fn daily_layout_is_the_same_however_the_ship_flies() {
+    // one ship hugs the sun, the other starts on the far side and is flown by
+    // the autopilot
+    let hugging = play_daily(false, |game| {
+        game.edit_player(|position: &mut ItemPosition| position.radius = 0.5);
+    });
+    let mut moved = false;
+    let piloted = play_daily(true, |game| {
+        if !std::mem::replace(&mut moved, true) {
+            game.edit_player(|position: &mut ItemPosition| position.theta += PI);
+        }
+    });
+
+    assert_eq!(hugging, piloted);
+}
@@ -0,0 +1,169 @@
+use bevy::prelude::*;
+
+use super::TestApp;
+use crate::{
+    DeathReason,
+    autopilot::Autopilot,
+    menus::Menu,
+    mode::GameMode,
+    obstacle::{AsteroidDebris, BlackHole, Obstacle},
+    player::{ItemPosition, Player, PlayerEffects, PlayerShield, ShieldAlarm},
+    save::SaveData,
+    score::Score,
+    screens::Screen,
+    sun::Sun,
+    supernova::{Nova, NovaTimer},
+};
+
+#[test]
+fn splash_leads_to_title_then_gameplay() {
+    let mut game = TestApp::new();
+    assert_eq!(game.screen(), Screen::Splash);
+
+    game.start_run(GameMode::Endless);
+    assert_eq!(*game.world().resource::<State<Menu>>().get(), Menu::None);
+    assert!(!game.has::<Autopilot>());
+    assert_eq!(game.nova(), Some(Nova::Idle));
+    assert!(game.has::<Score>());
+    assert!(game.has::<NovaTimer>());
+    assert!(!game.has::<ShieldAlarm>());
+    assert_eq!(game.count::<With<Player>>(), 1);
+
+    game.quit_to_title();
+    assert_eq!(game.nova(), None);
+    assert!(!game.has::<Score>());
+    assert!(!game.has::<NovaTimer>());
+
+    game.wait_for_title();
+    assert!(game.has::<Autopilot>());
+}
+
+#[test]
+fn attract_runs_play_behind_the_main_menu() {
+    let mut game = TestApp::new();
+    game.wait_for_title();
+    assert!(game.has::<Autopilot>());
+    assert_eq!(game.count::<With<Player>>(), 1);
+
+    // burning up goes straight to the next attract run, without the game over
+    // screen or closing the menu
+    game.edit_player(|position: &mut ItemPosition| position.radius = 0.5);
+    game.edit_player(|shield: &mut PlayerShield| shield.current = 0.0);
+    game.run_until(1.0, |world| {
+        *world.resource::<State<Screen>>() == Screen::Title
+    });
+    game.wait_for_title();
+
+    // a run from the menu is the player's, and the attract run earns nothing
+    // however well it did
+    game.world_mut().resource_mut::<Score>().score = 1_000_000.0;
+    game.start_run(GameMode::Endless);
+    assert!(!game.has::<Autopilot>());
+    assert_eq!(game.world().resource::<SaveData>().credits, 0);
+}
+
+#[test]
+fn nova_cycles_through_every_phase() {
+    let mut game = TestApp::new();
+    // no obstacles or shield damage to end the run early
+    game.start_run(GameMode::Zen);
+
+    for expected in [Nova::BuildingUp, Nova::During, Nova::After, Nova::Idle] {
+        assert_eq!(game.finish_nova_phase(), expected);
+        assert_eq!(game.screen(), Screen::Gameplay);
+        assert!(game.has::<NovaTimer>());
+    }
+
+    game.quit_to_title();
+    assert!(!game.has::<NovaTimer>());
+}
+
+#[test]
+fn burning_up_ends_the_run() {
+    let mut game = TestApp::new();
+    game.start_run(GameMode::Endless);
+
+    game.edit_player(|shield: &mut PlayerShield| shield.current = 0.2 * shield.max);
+    game.update();
+    assert!(game.has::<ShieldAlarm>());
+
+    // skim the sun with an empty shield
+    game.edit_player(|position: &mut ItemPosition| position.radius = 0.5);
+    game.edit_player(|shield: &mut PlayerShield| shield.current = 0.0);
+    game.run_until(1.0, |world| {
+        *world.resource::<State<Screen>>() == Screen::GameOver
+    });
+    assert_eq!(game.world().resource::<DeathReason>().0, "death.burned_up");
+
+    game.quit_to_title();
+    assert!(!game.has::<ShieldAlarm>());
+    assert!(!game.has::<Score>());
+}
+
+#[test]
+fn black_holes_end_the_run() {
+    let mut game = TestApp::new();
+    game.start_run(GameMode::Endless);
+
+    assert_eq!(game.finish_nova_phase(), Nova::BuildingUp);
+    assert_eq!(game.finish_nova_phase(), Nova::During);
+    assert!(game.count::<With<BlackHole>>() > 0);
+
+    // drag a black hole onto the ship until they collide
+    let mut frames = 0;
+    while game.screen() == Screen::Gameplay {
+        assert!(frames < 60, "the ship never hit the black hole");
+
+        let world = game.world_mut();
+        let ship = world
+            .query_filtered::<&Transform, With<Player>>()
+            .single(world)
+            .unwrap()
+            .translation;
+        let mut black_holes = world.query_filtered::<&mut Transform, With<BlackHole>>();
+        let mut black_hole = black_holes.iter_mut(world).next().unwrap();
+        black_hole.translation = ship.with_z(black_hole.translation.z);
+
+        game.update();
+        frames += 1;
+    }
+
+    assert_eq!(game.screen(), Screen::GameOver);
+    assert_eq!(game.world().resource::<DeathReason>().0, "death.black_hole");
+
+    game.quit_to_title();
+    assert_eq!(game.count::<With<BlackHole>>(), 0);
+}
+
+#[test]
+fn gameplay_entities_are_cleaned_up_between_runs() {
+    let mut game = TestApp::new();
+
+    for _ in 0..2 {
+        game.start_run(GameMode::Endless);
+        assert_eq!(game.count::<With<Player>>(), 1);
+        assert_eq!(game.count::<With<PlayerEffects>>(), 1);
+
+        // play long enough for obstacles to spawn, then crash out with the
+        // low shield alarm going
+        game.run_until(5.0, |world| {
+            world
+                .iter_entities()
+                .any(|entity| entity.contains::<Obstacle>())
+        });
+        game.edit_player(|shield: &mut PlayerShield| shield.current = 0.2 * shield.max);
+        game.update();
+        assert!(game.has::<ShieldAlarm>());
+        game.go_to(Screen::GameOver);
+        game.quit_to_title();
+
+        assert_eq!(game.count::<With<Player>>(), 0);
+        assert_eq!(game.count::<With<PlayerEffects>>(), 0);
+        assert_eq!(game.count::<With<Obstacle>>(), 0);
+        assert_eq!(game.count::<With<BlackHole>>(), 0);
+        assert_eq!(game.count::<With<AsteroidDebris>>(), 0);
+        assert_eq!(game.count::<With<Sun>>(), 0);
+        assert!(!game.has::<ShieldAlarm>());
+        assert!(!game.has::<NovaTimer>());
+    }
+}
@@ -0,0 +1,108 @@
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use bevy_enoki::{
+    Particle2dEffect, ParticleEffectHandle,
+    prelude::{ParticleEffectInstance, ParticleSpawnerState},
+};
+
+use super::TestApp;
+use crate::{
+    consts::SUN_STARTING_RADIUS,
+    mode::GameMode,
+    particles::{Effect, ParticleEffects},
+};
+
+fn spawn_body(game: &mut TestApp, position: Vec2, velocity: Vec2) -> Entity {
+    game.world_mut()
+        .spawn((
+            RigidBody::Dynamic,
+            Collider::circle(2.0),
+            Sensor,
+            Transform::from_translation(position.extend(0.0)),
+            LinearVelocity(velocity),
+        ))
+        .id()
+}
+
+#[test]
+fn bodies_fall_towards_the_sun() {
+    let mut game = TestApp::new();
+    game.start_run(GameMode::Endless);
+
+    let start = Vec2::new(0.0, SUN_STARTING_RADIUS + 500.0);
+    let body = spawn_body(&mut game, start, Vec2::ZERO);
+    for _ in 0..60 {
+        game.update();
+    }
+
+    let velocity = game.world().get::<LinearVelocity>(body).unwrap().0;
+    let position = game.world().get::<Position>(body).unwrap().0;
+    assert!(velocity.y < 0.0, "{velocity} should point at the sun");
+    assert!(
+        velocity.x.abs() < 1e-3,
+        "{velocity} should point at the sun"
+    );
+    assert!(position.y < start.y);
+}
+
+#[test]
+fn bodies_burn_up_falling_into_the_sun_but_not_rising_out() {
+    let mut game = TestApp::new();
+    game.start_run(GameMode::Endless);
+
+    let inside = Vec2::new(SUN_STARTING_RADIUS / 2.0, 0.0);
+    let falling = spawn_body(&mut game, inside, Vec2::new(-10.0, 0.0));
+    let rising = spawn_body(&mut game, inside, Vec2::new(100.0, 0.0));
+    for _ in 0..5 {
+        game.update();
+    }
+
+    assert!(game.world().get_entity(falling).is_err());
+    assert!(game.world().get_entity(rising).is_ok());
+}
+
+#[test]
+fn particles_are_aimed_at_the_sun_before_they_spawn() {
+    let mut game = TestApp::new();
+    game.start_run(GameMode::Endless);
+
+    // the particles don't run headless, so the spawner is filled in by hand
+    let handle = game
+        .world()
+        .resource::<ParticleEffects>()
+        .get(Effect::AsteroidHit);
+    let effect = game
+        .world()
+        .resource::<Assets<Particle2dEffect>>()
+        .get(&handle)
+        .unwrap()
+        .clone();
+    let distance = SUN_STARTING_RADIUS + 300.0;
+    let spawner = game
+        .world_mut()
+        .spawn((
+            ParticleSpawnerState::default(),
+            ParticleEffectInstance(Some(effect)),
+            ParticleEffectHandle(handle),
+            Transform::from_xyz(distance, 0.0, 0.0),
+            GravityScale(75.0),
+        ))
+        .id();
+    assert!(
+        game.world()
+            .get::<ParticleSpawnerState>(spawner)
+            .unwrap()
+            .timer
+            .paused()
+    );
+
+    game.update();
+    let state = game.world().get::<ParticleSpawnerState>(spawner).unwrap();
+    assert!(!state.timer.paused());
+    let instance = game.world().get::<ParticleEffectInstance>(spawner).unwrap();
+    let instance = instance.0.as_ref().unwrap();
+    assert_eq!(instance.gravity_direction.as_ref().unwrap().0, Vec2::NEG_X);
+    assert_eq!(instance.gravity_speed.as_ref().unwrap().0, 150.0);
+    // they fade on reaching the surface, 300 pixels away
+    assert!((instance.lifetime.0 - 2.0).abs() < 1e-3);
+}
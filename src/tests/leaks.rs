@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+
+use super::TestApp;
+use crate::{
+    autopilot::Autopilot,
+    leaks::{EntityCensus, LastCensus},
+    mode::GameMode,
+    obstacle::Obstacle,
+    screens::Screen,
+};
+
+fn last_census(game: &TestApp) -> EntityCensus {
+    game.world()
+        .resource::<LastCensus>()
+        .0
+        .clone()
+        .expect("a census is taken on entering the title screen")
+}
+
+#[test]
+fn entity_counts_are_stable_over_many_runs() {
+    let mut game = TestApp::new();
+    game.run_until(5.0, |world| {
+        *world.resource::<State<Screen>>() == Screen::Title
+    });
+    let before = last_census(&game);
+    let mut first_run = None;
+
+    for run in 1..=10 {
+        game.start_run(GameMode::Endless);
+        game.world_mut().insert_resource(Autopilot);
+
+        // play until obstacles are about, then through a whole nova, unless
+        // the autopilot crashes first
+        game.run_until(5.0, |world| {
+            world
+                .iter_entities()
+                .any(|entity| entity.contains::<Obstacle>())
+        });
+        for _ in 0..4 {
+            if game.screen() == Screen::Gameplay {
+                game.finish_nova_phase();
+            }
+        }
+
+        if game.screen() == Screen::Gameplay {
+            game.go_to(Screen::GameOver);
+        }
+        game.quit_to_title();
+
+        let after = last_census(&game);
+        let growth: Vec<_> = after.growth_since(&before).collect();
+        assert!(
+            growth.is_empty(),
+            "run {run} left entities behind: {growth:#?}"
+        );
+        // the first run may tidy up after loading, but after that the counts
+        // must hold steady
+        let first_run = first_run.get_or_insert(after.clone());
+        assert_eq!(&after, first_run, "run {run} changed the entity counts");
+    }
+}
@@ -0,0 +1,209 @@
+//! Scales the UI and picks a layout to suit the shape of the window, so the
+//! HUD and menus still fit on small and portrait screens.
+
+use bevy::{
+    ecs::component::Mutable,
+    input::mouse::{MouseScrollUnit, MouseWheel},
+    prelude::*,
+    ui::{UiSystem, Val::*},
+    window::PrimaryWindow,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::save::SaveData;
+
+/// Windows narrower than this, in UI pixels after scaling, use the narrow
+/// layout
+const NARROW_WIDTH: f32 = 720.0;
+
+/// How far one line of the mouse wheel scrolls
+const SCROLL_LINE_HEIGHT: f32 = 24.0;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<ScreenLayout>();
+    app.register_type::<Responsive<Node>>();
+    app.register_type::<Responsive<TextFont>>();
+
+    app.init_resource::<ScreenLayout>();
+
+    app.add_systems(
+        PreUpdate,
+        (
+            apply_ui_scale.run_if(resource_changed::<SaveData>),
+            select_screen_layout,
+        )
+            .chain(),
+    );
+    app.add_systems(Update, scroll_overflowing_nodes);
+    app.add_systems(
+        PostUpdate,
+        (apply_responsive::<Node>, apply_responsive::<TextFont>).before(UiSystem::Prepare),
+    );
+}
+
+/// The size of the UI as a percentage, chosen in the settings
+#[derive(Reflect, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct UiScaleSetting(pub u32);
+
+impl UiScaleSetting {
+    const MIN: u32 = 50;
+    const MAX: u32 = 200;
+    const STEP: u32 = 10;
+
+    pub fn smaller(self) -> Self {
+        Self(self.0.saturating_sub(Self::STEP).max(Self::MIN))
+    }
+
+    pub fn larger(self) -> Self {
+        Self((self.0 + Self::STEP).min(Self::MAX))
+    }
+
+    fn factor(self) -> f32 {
+        self.0.clamp(Self::MIN, Self::MAX) as f32 / 100.0
+    }
+}
+
+impl Default for UiScaleSetting {
+    fn default() -> Self {
+        Self(100)
+    }
+}
+
+/// The layout the UI is arranged in, based on the window's size
+#[derive(Resource, Reflect, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[reflect(Resource)]
+pub enum ScreenLayout {
+    #[default]
+    Wide,
+    /// Phones, portrait windows and large UI scales
+    Narrow,
+}
+
+/// A component with a value for each [`ScreenLayout`], which replaces the
+/// entity's component whenever the layout changes
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component)]
+pub struct Responsive<T> {
+    pub wide: T,
+    pub narrow: T,
+}
+
+impl<T> Responsive<T> {
+    pub fn get(&self, layout: ScreenLayout) -> &T {
+        match layout {
+            ScreenLayout::Wide => &self.wide,
+            ScreenLayout::Narrow => &self.narrow,
+        }
+    }
+}
+
+/// A component that switches between the two values with the layout
+pub fn responsive<T: Component + Clone>(wide: T, narrow: T) -> impl Bundle {
+    (wide.clone(), Responsive { wide, narrow })
+}
+
+/// A grid that stacks its cells in a single centered column in the narrow
+/// layout, scrolling if they no longer fit on the screen
+pub fn stacking_grid(wide: Node) -> impl Bundle {
+    let narrow = Node {
+        width: Percent(100.0),
+        min_height: Val::ZERO,
+        overflow: Overflow::scroll_y(),
+        column_gap: Val::ZERO,
+        grid_template_columns: vec![GridTrack::flex(1.0)],
+        justify_items: JustifyItems::Center,
+        ..wide.clone()
+    };
+    responsive(wide, narrow)
+}
+
+/// A cell of a [`stacking_grid`] that is aligned in the wide layout, and
+/// centered once the grid is stacked
+pub fn grid_cell(justify_self: JustifySelf) -> impl Bundle {
+    responsive(
+        Node {
+            justify_self,
+            ..default()
+        },
+        Node {
+            justify_self: JustifySelf::Center,
+            ..default()
+        },
+    )
+}
+
+/// A font size for the wide and narrow layouts
+pub fn font_size(wide: f32, narrow: f32) -> impl Bundle {
+    responsive(
+        TextFont::from_font_size(wide),
+        TextFont::from_font_size(narrow),
+    )
+}
+
+fn apply_ui_scale(save: Res<SaveData>, mut ui_scale: ResMut<UiScale>) {
+    let factor = save.ui_scale.factor();
+    if ui_scale.0 != factor {
+        ui_scale.0 = factor;
+    }
+}
+
+fn select_screen_layout(
+    window: Option<Single<&Window, With<PrimaryWindow>>>,
+    ui_scale: Res<UiScale>,
+    mut layout: ResMut<ScreenLayout>,
+) {
+    let Some(window) = window else {
+        return;
+    };
+
+    let portrait = window.height() > window.width();
+    let narrow = window.width() / ui_scale.0 < NARROW_WIDTH;
+    layout.set_if_neq(if portrait || narrow {
+        ScreenLayout::Narrow
+    } else {
+        ScreenLayout::Wide
+    });
+}
+
+/// Scrolls any node that overflows, with the mouse wheel or by dragging a
+/// finger across the screen
+fn scroll_overflowing_nodes(
+    mut wheel: EventReader<MouseWheel>,
+    touches: Res<Touches>,
+    mut nodes: Query<(&Node, &mut ScrollPosition)>,
+) {
+    let mut delta = 0.0;
+    for event in wheel.read() {
+        delta -= match event.unit {
+            MouseScrollUnit::Line => event.y * SCROLL_LINE_HEIGHT,
+            MouseScrollUnit::Pixel => event.y,
+        };
+    }
+    for touch in touches.iter() {
+        delta -= touch.delta().y;
+    }
+
+    if delta == 0.0 {
+        return;
+    }
+
+    for (node, mut scroll) in &mut nodes {
+        if node.overflow.y == OverflowAxis::Scroll {
+            scroll.offset_y += delta;
+        }
+    }
+}
+
+fn apply_responsive<T: Component<Mutability = Mutable> + Clone>(
+    layout: Res<ScreenLayout>,
+    mut query: Query<(Ref<Responsive<T>>, &mut T)>,
+) {
+    for (responsive, mut value) in &mut query {
+        if !layout.is_changed() && !responsive.is_added() {
+            continue;
+        }
+
+        *value = responsive.get(*layout).clone();
+    }
+}
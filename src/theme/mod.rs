@@ -4,16 +4,17 @@
 #![allow(dead_code)]
 
 pub mod interaction;
+pub mod layout;
 pub mod palette;
 pub mod widget;
 
 #[allow(unused_imports)]
 pub mod prelude {
-    pub use super::{interaction::InteractionPalette, palette as ui_palette, widget};
+    pub use super::{interaction::InteractionPalette, layout, palette as ui_palette, widget};
 }
 
 use bevy::prelude::*;
 
 pub(super) fn plugin(app: &mut App) {
-    app.add_plugins(interaction::plugin);
+    app.add_plugins((interaction::plugin, layout::plugin, palette::plugin));
 }
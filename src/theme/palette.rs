@@ -1,6 +1,17 @@
-use bevy::prelude::*;
+use bevy::{
+    color::palettes::{
+        css::{DARK_CYAN, ORANGE, RED, WHITE, YELLOW},
+        tailwind::{AMBER_400, EMERALD_400},
+    },
+    prelude::*,
+};
+use serde::{Deserialize, Serialize};
 
-use crate::consts::MAIN_THEME_COLOR;
+use crate::{
+    consts::{BLOOMED_WHITE, INNER_SUN_COLOUR, MAIN_THEME_COLOR, OBSTACLE_COLOR, SUN_COLOUR},
+    save::SaveData,
+    theme::interaction::InteractionPalette,
+};
 
 /// #ddd369
 pub const LABEL_TEXT: Color = Color::srgb(0.867, 0.827, 0.412);
@@ -16,3 +27,243 @@ pub const BUTTON_BACKGROUND: Color = MAIN_THEME_COLOR;
 pub const BUTTON_HOVERED_BACKGROUND: Color = Color::hsla(21.0, 0.836, 0.45, 1.0);
 /// #3d4999
 pub const BUTTON_PRESSED_BACKGROUND: Color = Color::hsla(21.0, 0.966, 0.31, 1.0);
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<PaletteTheme>();
+    app.register_type::<Palette>();
+    app.register_type::<PaletteText>();
+    app.register_type::<PaletteButton>();
+
+    app.init_resource::<Palette>();
+
+    app.add_systems(
+        PreUpdate,
+        select_palette.run_if(resource_changed::<SaveData>),
+    );
+    app.add_systems(Update, (apply_text_palette, apply_button_palette));
+}
+
+/// The colour presets the player can choose from in the settings
+#[derive(Reflect, Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PaletteTheme {
+    #[default]
+    Default,
+    Deuteranopia,
+    Protanopia,
+    Tritanopia,
+    HighContrast,
+}
+
+impl PaletteTheme {
+    /// The locale key for the theme's name
+    pub fn name_key(&self) -> &'static str {
+        match self {
+            PaletteTheme::Default => "palette.default",
+            PaletteTheme::Deuteranopia => "palette.deuteranopia",
+            PaletteTheme::Protanopia => "palette.protanopia",
+            PaletteTheme::Tritanopia => "palette.tritanopia",
+            PaletteTheme::HighContrast => "palette.high_contrast",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            PaletteTheme::Default => PaletteTheme::Deuteranopia,
+            PaletteTheme::Deuteranopia => PaletteTheme::Protanopia,
+            PaletteTheme::Protanopia => PaletteTheme::Tritanopia,
+            PaletteTheme::Tritanopia => PaletteTheme::HighContrast,
+            PaletteTheme::HighContrast => PaletteTheme::Default,
+        }
+    }
+
+    pub fn previous(&self) -> Self {
+        match self {
+            PaletteTheme::Default => PaletteTheme::HighContrast,
+            PaletteTheme::Deuteranopia => PaletteTheme::Default,
+            PaletteTheme::Protanopia => PaletteTheme::Deuteranopia,
+            PaletteTheme::Tritanopia => PaletteTheme::Protanopia,
+            PaletteTheme::HighContrast => PaletteTheme::Tritanopia,
+        }
+    }
+
+    pub fn palette(&self) -> Palette {
+        let default = Palette::default();
+
+        // the colourblind presets are based on the Okabe-Ito palette, with
+        // the gameplay colours pushed past 1.0 so they still bloom
+        match self {
+            PaletteTheme::Default => default,
+            PaletteTheme::Deuteranopia => Palette {
+                sun: Color::srgba(3.0, 1.6, 0.0, 1.0),
+                inner_sun: Color::srgba(3.5, 2.4, 0.2, 1.0),
+                obstacle: Color::srgba(0.6, 2.4, 5.0, 1.0),
+                warp_tint: Color::srgb(0.0, 0.447, 0.698),
+                power_bar: Color::srgb(0.0, 0.447, 0.698),
+                shield_bar: Color::srgb(0.941, 0.894, 0.259),
+                combo_bar: Color::srgb(0.8, 0.475, 0.655),
+                danger: Color::srgb(0.941, 0.894, 0.259),
+                caution: Color::srgb(0.337, 0.706, 0.914),
+                warning: Color::srgb(0.0, 0.447, 0.698),
+                ..default
+            },
+            PaletteTheme::Protanopia => Palette {
+                sun: Color::srgba(3.2, 2.2, 0.1, 1.0),
+                inner_sun: Color::srgba(3.8, 3.0, 0.4, 1.0),
+                obstacle: Color::srgba(0.6, 2.4, 5.0, 1.0),
+                warp_tint: Color::srgb(0.0, 0.447, 0.698),
+                power_bar: Color::srgb(0.0, 0.447, 0.698),
+                shield_bar: Color::srgb(0.941, 0.894, 0.259),
+                combo_bar: Color::srgb(0.8, 0.475, 0.655),
+                danger: Color::srgb(0.941, 0.894, 0.259),
+                caution: Color::srgb(0.337, 0.706, 0.914),
+                warning: Color::srgb(0.0, 0.447, 0.698),
+                ..default
+            },
+            PaletteTheme::Tritanopia => Palette {
+                sun: Color::srgba(3.5, 0.5, 0.6, 1.0),
+                inner_sun: Color::srgba(4.0, 1.0, 1.0, 1.0),
+                obstacle: Color::srgba(1.5, 3.5, 3.5, 1.0),
+                warp_tint: Color::srgb(0.0, 0.62, 0.451),
+                power_bar: Color::srgb(0.0, 0.62, 0.451),
+                shield_bar: WHITE.into(),
+                combo_bar: Color::srgb(0.8, 0.475, 0.655),
+                danger: Color::srgb(0.835, 0.369, 0.0),
+                caution: Color::srgb(0.8, 0.475, 0.655),
+                warning: Color::srgb(0.0, 0.62, 0.451),
+                ..default
+            },
+            PaletteTheme::HighContrast => Palette {
+                sun: Color::srgba(4.0, 4.0, 0.5, 1.0),
+                inner_sun: Color::srgba(4.0, 4.0, 2.0, 1.0),
+                obstacle: Color::srgba(5.0, 5.0, 5.0, 1.0),
+                warp_tint: Color::srgb(0.0, 1.0, 1.0),
+                power_bar: YELLOW.into(),
+                shield_bar: WHITE.into(),
+                combo_bar: Color::srgb(0.0, 1.0, 1.0),
+                danger: WHITE.into(),
+                caution: YELLOW.into(),
+                warning: Color::srgb(0.0, 1.0, 1.0),
+                label_text: WHITE.into(),
+                header_text: YELLOW.into(),
+                button_text: Color::BLACK,
+                button_background: YELLOW.into(),
+                button_hovered_background: WHITE.into(),
+                button_pressed_background: Color::srgb(0.7, 0.7, 0.0),
+                ..default
+            },
+        }
+    }
+}
+
+/// The colours used across the game, chosen from the [`PaletteTheme`] in
+/// the player's save data
+#[derive(Resource, Reflect, Debug, Clone, PartialEq)]
+#[reflect(Resource)]
+pub struct Palette {
+    pub sun: Color,
+    pub inner_sun: Color,
+    pub obstacle: Color,
+    /// The flash when the sun goes nova
+    pub nova_flash: Color,
+    /// The player's colour while warping
+    pub warp_tint: Color,
+    pub power_bar: Color,
+    pub shield_bar: Color,
+    pub combo_bar: Color,
+    pub bar_border: Color,
+    /// Threats that are about to be hit, then the less urgent ones
+    pub danger: Color,
+    pub caution: Color,
+    pub warning: Color,
+    pub label_text: Color,
+    pub header_text: Color,
+    pub button_text: Color,
+    pub button_background: Color,
+    pub button_hovered_background: Color,
+    pub button_pressed_background: Color,
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self {
+            sun: SUN_COLOUR,
+            inner_sun: INNER_SUN_COLOUR,
+            obstacle: OBSTACLE_COLOR,
+            nova_flash: BLOOMED_WHITE,
+            warp_tint: DARK_CYAN.into(),
+            power_bar: EMERALD_400.into(),
+            shield_bar: WHITE.into(),
+            combo_bar: AMBER_400.into(),
+            bar_border: WHITE.into(),
+            danger: RED.into(),
+            caution: ORANGE.into(),
+            warning: YELLOW.into(),
+            label_text: LABEL_TEXT,
+            header_text: HEADER_TEXT,
+            button_text: BUTTON_TEXT,
+            button_background: BUTTON_BACKGROUND,
+            button_hovered_background: BUTTON_HOVERED_BACKGROUND,
+            button_pressed_background: BUTTON_PRESSED_BACKGROUND,
+        }
+    }
+}
+
+/// Colours the text with the palette, keeping it up to date when the palette
+/// changes
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub enum PaletteText {
+    Label,
+    Header,
+    Button,
+}
+
+/// Keeps a button's [`InteractionPalette`] up to date with the palette
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+pub struct PaletteButton;
+
+fn select_palette(save: Res<SaveData>, mut palette: ResMut<Palette>) {
+    palette.set_if_neq(save.palette.palette());
+}
+
+fn apply_text_palette(palette: Res<Palette>, mut texts: Query<(Ref<PaletteText>, &mut TextColor)>) {
+    for (role, mut color) in &mut texts {
+        if !palette.is_changed() && !role.is_added() {
+            continue;
+        }
+
+        color.0 = match *role {
+            PaletteText::Label => palette.label_text,
+            PaletteText::Header => palette.header_text,
+            PaletteText::Button => palette.button_text,
+        };
+    }
+}
+
+fn apply_button_palette(
+    palette: Res<Palette>,
+    mut buttons: Query<(
+        Ref<PaletteButton>,
+        &Interaction,
+        &mut InteractionPalette,
+        &mut BackgroundColor,
+    )>,
+) {
+    for (marker, interaction, mut colors, mut background) in &mut buttons {
+        if !palette.is_changed() && !marker.is_added() {
+            continue;
+        }
+
+        *colors = InteractionPalette {
+            none: palette.button_background,
+            hovered: palette.button_hovered_background,
+            pressed: palette.button_pressed_background,
+        };
+        background.0 = match interaction {
+            Interaction::None => colors.none,
+            Interaction::Hovered => colors.hovered,
+            Interaction::Pressed => colors.pressed,
+        };
+    }
+}
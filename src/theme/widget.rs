@@ -8,7 +8,14 @@ use bevy::{
     ui::Val::*,
 };
 
-use crate::theme::{interaction::InteractionPalette, palette::*};
+use crate::{
+    locale::UiText,
+    theme::{
+        interaction::InteractionPalette,
+        layout,
+        palette::{PaletteButton, PaletteText, *},
+    },
+};
 
 /// A root UI node that fills the window and centers its content.
 pub fn ui_root(name: impl Into<Cow<'static, str>>) -> impl Bundle {
@@ -22,6 +29,7 @@ pub fn ui_root(name: impl Into<Cow<'static, str>>) -> impl Bundle {
             justify_content: JustifyContent::Center,
             flex_direction: FlexDirection::Column,
             row_gap: Px(20.0),
+            padding: UiRect::horizontal(Px(20.0)),
             ..default()
         },
         // Don't block picking events for other UI roots.
@@ -30,27 +38,34 @@ pub fn ui_root(name: impl Into<Cow<'static, str>>) -> impl Bundle {
 }
 
 /// A simple header label. Bigger than [`label`].
-pub fn header(text: impl Into<String>) -> impl Bundle {
+pub fn header(text: impl Into<UiText>) -> impl Bundle {
+    let text = text.into();
     (
         Name::new("Header"),
-        Text(text.into()),
-        TextFont::from_font_size(40.0),
+        Text(text.initial()),
+        text,
+        layout::font_size(40.0, 30.0),
         TextColor(HEADER_TEXT),
+        PaletteText::Header,
     )
 }
 
 /// A simple text label.
-pub fn label(text: impl Into<String>) -> impl Bundle {
+pub fn label(text: impl Into<UiText>) -> impl Bundle {
+    let text = text.into();
     (
         Name::new("Label"),
-        Text(text.into()),
-        TextFont::from_font_size(24.0),
+        Text(text.initial()),
+        text,
+        layout::font_size(24.0, 18.0),
+        TextLayout::new_with_justify(JustifyText::Center),
         TextColor(LABEL_TEXT),
+        PaletteText::Label,
     )
 }
 
 /// A large rounded button with text and an action defined as an [`Observer`].
-pub fn button<E, B, M, I>(text: impl Into<String>, action: I) -> impl Bundle
+pub fn button<E, B, M, I>(text: impl Into<UiText>, action: I) -> impl Bundle
 where
     E: Event,
     B: Bundle,
@@ -60,20 +75,29 @@ where
         text,
         action,
         (
-            Node {
-                width: Px(380.0),
-                height: Px(80.0),
-                align_items: AlignItems::Center,
-                justify_content: JustifyContent::Center,
-                ..default()
-            },
+            layout::responsive(
+                Node {
+                    width: Px(380.0),
+                    height: Px(80.0),
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                Node {
+                    width: Px(260.0),
+                    height: Px(60.0),
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+            ),
             BorderRadius::MAX,
         ),
     )
 }
 
 /// A smaller button with text and an action defined as an [`Observer`].
-pub fn menu_button<E, B, M, I>(text: impl Into<String>, action: I) -> impl Bundle
+pub fn menu_button<E, B, M, I>(text: impl Into<UiText>, action: I) -> impl Bundle
 where
     E: Event,
     B: Bundle,
@@ -96,7 +120,7 @@ where
 }
 
 /// A small square button with text and an action defined as an [`Observer`].
-pub fn button_small<E, B, M, I>(text: impl Into<String>, action: I) -> impl Bundle
+pub fn button_small<E, B, M, I>(text: impl Into<UiText>, action: I) -> impl Bundle
 where
     E: Event,
     B: Bundle,
@@ -117,7 +141,7 @@ where
 
 /// A simple button with text and an action defined as an [`Observer`]. The button's layout is provided by `button_bundle`.
 fn button_base<E, B, M, I>(
-    text: impl Into<String>,
+    text: impl Into<UiText>,
     action: I,
     button_bundle: impl Bundle,
 ) -> impl Bundle
@@ -142,11 +166,14 @@ where
                         hovered: BUTTON_HOVERED_BACKGROUND,
                         pressed: BUTTON_PRESSED_BACKGROUND,
                     },
+                    PaletteButton,
                     children![(
                         Name::new("Button Text"),
-                        Text(text),
+                        Text(text.initial()),
+                        text,
                         TextFont::from_font_size(20.0),
                         TextColor(BUTTON_TEXT),
+                        PaletteText::Button,
                         // Don't bubble picking events from the text up to the button.
                         Pickable::IGNORE,
                     )],
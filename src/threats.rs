@@ -0,0 +1,277 @@
+//! Arrows at the edge of the screen pointing at off-screen threats on the
+//! player's path, coloured by how soon the player will reach them.
+
+use std::f32::consts::TAU;
+
+use avian2d::prelude::LinearVelocity;
+use bevy::{platform::collections::HashMap, prelude::*};
+
+use crate::{
+    camera::update_camera,
+    mode::GameMode,
+    obstacle::{BlackHole, Obstacle},
+    player::{ItemPosition, Player},
+    screens::Screen,
+    sun::Sun,
+    theme::palette::Palette,
+};
+
+/// Threats further away than this are not shown
+const MAX_WARNING_TIME: f32 = 4.0;
+/// Threats reached sooner than this are shown in red, then orange
+const DANGER_TIME: f32 = 1.5;
+const CAUTION_TIME: f32 = 2.5;
+/// How far the threat can be from the player's current radius when the player
+/// reaches it and still count as being on their path
+const PATH_MARGIN: f32 = 120.0;
+/// The distance of the arrows from the edge of the screen
+const EDGE_MARGIN: f32 = 24.0;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<ThreatArrow>();
+    app.register_type::<ThreatArrowAssets>();
+    app.register_type::<LastTheta>();
+
+    app.init_resource::<ThreatArrowAssets>();
+    app.init_resource::<LastTheta>();
+
+    app.add_systems(OnEnter(Screen::Gameplay), reset_last_theta);
+    app.add_systems(
+        PostUpdate,
+        update_threat_arrows
+            .after(update_camera)
+            .before(TransformSystem::TransformPropagate)
+            .run_if(in_state(Screen::Gameplay).and(not(resource_equals(GameMode::Attract)))),
+    );
+    app.add_systems(
+        Update,
+        apply_threat_palette.run_if(resource_changed::<Palette>),
+    );
+}
+
+#[derive(Resource, Reflect)]
+#[reflect(Resource)]
+struct ThreatArrowAssets {
+    mesh: Handle<Mesh>,
+    danger: Handle<ColorMaterial>,
+    caution: Handle<ColorMaterial>,
+    warning: Handle<ColorMaterial>,
+}
+
+impl FromWorld for ThreatArrowAssets {
+    fn from_world(world: &mut World) -> Self {
+        let mesh = world.resource_mut::<Assets<Mesh>>().add(Triangle2d::new(
+            Vec2::X * 10.0,
+            Vec2::new(-6.0, 7.0),
+            Vec2::new(-6.0, -7.0),
+        ));
+
+        let palette = world.resource::<Palette>().clone();
+        let mut materials = world.resource_mut::<Assets<ColorMaterial>>();
+        Self {
+            mesh,
+            danger: materials.add(palette.danger),
+            caution: materials.add(palette.caution),
+            warning: materials.add(palette.warning),
+        }
+    }
+}
+
+impl ThreatArrowAssets {
+    fn material(&self, time_to_impact: f32) -> Handle<ColorMaterial> {
+        if time_to_impact < DANGER_TIME {
+            self.danger.clone()
+        } else if time_to_impact < CAUTION_TIME {
+            self.caution.clone()
+        } else {
+            self.warning.clone()
+        }
+    }
+}
+
+/// The ship's angle last frame, to measure how quickly it orbits
+#[derive(Resource, Reflect, Debug, Default)]
+#[reflect(Resource)]
+struct LastTheta(Option<f32>);
+
+fn reset_last_theta(mut last_theta: ResMut<LastTheta>) {
+    *last_theta = LastTheta::default();
+}
+
+fn apply_threat_palette(
+    palette: Res<Palette>,
+    assets: Res<ThreatArrowAssets>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    for (handle, color) in [
+        (&assets.danger, palette.danger),
+        (&assets.caution, palette.caution),
+        (&assets.warning, palette.warning),
+    ] {
+        if let Some(material) = materials.get_mut(handle) {
+            material.color = color;
+        }
+    }
+}
+
+/// An arrow pointing at the target threat
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct ThreatArrow {
+    target: Entity,
+}
+
+/// When the player will next pass a threat, and how far from the sun's
+/// surface the threat will be by then
+#[derive(Debug, Clone, Copy)]
+pub struct Approach {
+    pub time: f32,
+    pub radius: f32,
+}
+
+/// Predicts when the player, orbiting at `angular_speed`, will next pass the
+/// threat at `position`
+pub fn approach(
+    angular_speed: f32,
+    player: &ItemPosition,
+    sun_radius: f32,
+    position: Vec2,
+    velocity: Vec2,
+) -> Option<Approach> {
+    if angular_speed <= 0.0 {
+        return None;
+    }
+
+    // theta is measured clockwise from the y axis
+    let theta = position.x.atan2(position.y);
+    let gap = (theta - player.theta).rem_euclid(TAU);
+    let time = gap / angular_speed;
+
+    // obstacles drift radially, so check where it will be when the player
+    // gets there
+    let radial_speed = velocity.dot(position.normalize_or_zero());
+    let radius = position.length() - sun_radius + radial_speed * time;
+
+    Some(Approach { time, radius })
+}
+
+/// Estimates how long until the player reaches a threat, if it is on the
+/// player's path at all
+fn time_to_impact(
+    angular_speed: f32,
+    player: &ItemPosition,
+    sun_radius: f32,
+    position: Vec2,
+    velocity: Vec2,
+) -> Option<f32> {
+    let Approach { time, radius } =
+        approach(angular_speed, player, sun_radius, position, velocity)?;
+
+    (time <= MAX_WARNING_TIME && (radius - player.radius).abs() <= PATH_MARGIN).then_some(time)
+}
+
+fn update_threat_arrows(
+    mut commands: Commands,
+    time: Res<Time>,
+    assets: Res<ThreatArrowAssets>,
+    mut last_theta: ResMut<LastTheta>,
+    camera: Single<(&Camera, &Transform, &Projection), (With<Camera2d>, Without<ThreatArrow>)>,
+    sun: Single<&Sun>,
+    player: Single<&ItemPosition, With<Player>>,
+    threats: Query<
+        (Entity, &Transform, Option<&LinearVelocity>),
+        (Or<(With<Obstacle>, With<BlackHole>)>, Without<ThreatArrow>),
+    >,
+    mut arrows: Query<(
+        Entity,
+        &ThreatArrow,
+        &mut Transform,
+        &mut MeshMaterial2d<ColorMaterial>,
+    )>,
+) {
+    // measure the angular speed directly so boosts and skimming are included
+    let angular_speed = match last_theta.0.replace(player.theta) {
+        Some(last) if time.delta_secs() > 0.0 => (player.theta - last) / time.delta_secs(),
+        _ => 0.0,
+    };
+
+    // work in the camera's space, as it may be zoomed and rotated
+    let (camera, camera_tx, projection) = camera.into_inner();
+    let Some(viewport) = camera.logical_viewport_rect() else {
+        return;
+    };
+    let zoom = match projection {
+        Projection::Orthographic(orthographic) => orthographic.scale,
+        _ => 1.0,
+    };
+    let view = ArrowView {
+        camera: *camera_tx,
+        half_size: viewport.half_size() * zoom,
+        inset: (viewport.half_size() - EDGE_MARGIN).max(Vec2::ONE) * zoom,
+        zoom,
+    };
+
+    let mut active: HashMap<Entity, (Vec2, f32)> = HashMap::default();
+    for (entity, tx, velocity) in &threats {
+        let position = tx.translation.truncate();
+        let local = view.to_local(position);
+        if local.abs().cmple(view.half_size).all() {
+            continue;
+        }
+
+        let velocity = velocity.map(|v| v.0).unwrap_or_default();
+        if let Some(time) = time_to_impact(angular_speed, &player, sun.radius, position, velocity) {
+            active.insert(entity, (position, time));
+        }
+    }
+
+    for (entity, arrow, mut tx, mut material) in &mut arrows {
+        match active.remove(&arrow.target) {
+            Some((position, time)) => {
+                *tx = view.arrow_transform(position);
+                material.0 = assets.material(time);
+            }
+            None => commands.entity(entity).despawn(),
+        }
+    }
+
+    for (target, (position, time)) in active {
+        commands.spawn((
+            Name::new("Threat Arrow"),
+            ThreatArrow { target },
+            StateScoped(Screen::Gameplay),
+            Mesh2d(assets.mesh.clone()),
+            MeshMaterial2d(assets.material(time)),
+            view.arrow_transform(position),
+        ));
+    }
+}
+
+/// The visible area of the world, in the camera's space
+struct ArrowView {
+    camera: Transform,
+    half_size: Vec2,
+    /// The half size of the area the arrows are placed on
+    inset: Vec2,
+    zoom: f32,
+}
+
+impl ArrowView {
+    fn to_local(&self, position: Vec2) -> Vec2 {
+        let offset = position.extend(0.0) - self.camera.translation.with_z(0.0);
+        (self.camera.rotation.inverse() * offset).truncate()
+    }
+
+    /// Places the arrow on the edge of the screen, pointing towards the target
+    fn arrow_transform(&self, target: Vec2) -> Transform {
+        let direction = self.to_local(target);
+        let scale = (self.inset.x / direction.x.abs()).min(self.inset.y / direction.y.abs());
+        let edge = self.camera.rotation * (direction * scale).extend(0.0);
+
+        Transform::from_translation(
+            (self.camera.translation.truncate() + edge.truncate()).extend(0.5),
+        )
+        .with_rotation(self.camera.rotation * Quat::from_rotation_z(direction.to_angle()))
+        .with_scale(Vec3::splat(self.zoom))
+    }
+}
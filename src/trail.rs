@@ -0,0 +1,228 @@
+//! Ribbon trails that follow any entity with a [`Trail`].
+//!
+//! Each trail records where its entity has been and draws a single mesh
+//! through those points, tapering and fading towards the far end. The mesh
+//! lives on a separate entity in world space, so it doesn't move or rotate
+//! with the entity, and is despawned along with it.
+
+use std::collections::VecDeque;
+
+use bevy::{
+    asset::RenderAssetUsages,
+    prelude::*,
+    render::{mesh::PrimitiveTopology, view::NoFrustumCulling},
+};
+
+/// How far behind the entity the ribbon is drawn
+const BEHIND: f32 = 0.3;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<Trail>();
+    app.register_type::<TrailRibbon>();
+
+    app.init_resource::<TrailMaterial>();
+
+    app.add_observer(spawn_ribbon);
+    app.add_observer(despawn_ribbon);
+    app.add_systems(
+        PostUpdate,
+        (record_trails, draw_ribbons)
+            .chain()
+            .after(TransformSystem::TransformPropagate),
+    );
+}
+
+/// Leaves a ribbon behind the entity as it moves
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component)]
+pub struct Trail {
+    /// The most points the ribbon passes through, one recorded each frame
+    pub length: usize,
+    /// How long each point lasts, fading out as it goes
+    pub lifetime: f32,
+    /// The ribbon's width at the entity and at its far end
+    pub width: (f32, f32),
+    /// The ribbon's colour at the entity and at its far end
+    pub color: (Color, Color),
+    /// Whether new points are recorded. The ribbon fades away while this is
+    /// false.
+    pub emitting: bool,
+    /// Where the entity has been, newest first
+    #[reflect(ignore)]
+    points: VecDeque<TrailPoint>,
+}
+
+impl Trail {
+    pub fn new(length: usize, lifetime: f32) -> Self {
+        Self {
+            length,
+            lifetime,
+            width: (4.0, 0.0),
+            color: (Color::WHITE, Color::WHITE.with_alpha(0.0)),
+            emitting: true,
+            points: VecDeque::with_capacity(length),
+        }
+    }
+
+    pub fn with_width(mut self, start: f32, end: f32) -> Self {
+        self.width = (start, end);
+        self
+    }
+
+    pub fn with_color(mut self, start: impl Into<Color>, end: impl Into<Color>) -> Self {
+        self.color = (start.into(), end.into());
+        self
+    }
+
+    /// Removes the ribbon at once, so it starts afresh from wherever the
+    /// entity is next
+    pub fn clear(&mut self) {
+        self.points.clear();
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TrailPoint {
+    position: Vec3,
+    age: f32,
+}
+
+/// The entity drawing the trail's ribbon
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+pub struct TrailRibbon(Entity);
+
+/// Shared by every ribbon, which are coloured by their vertices instead
+#[derive(Resource)]
+struct TrailMaterial(Handle<ColorMaterial>);
+
+impl FromWorld for TrailMaterial {
+    fn from_world(world: &mut World) -> Self {
+        let mut materials = world.resource_mut::<Assets<ColorMaterial>>();
+        Self(materials.add(ColorMaterial::default()))
+    }
+}
+
+fn spawn_ribbon(
+    trigger: Trigger<OnAdd, Trail>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    material: Res<TrailMaterial>,
+) {
+    let mesh = Mesh::new(
+        PrimitiveTopology::TriangleStrip,
+        RenderAssetUsages::default(),
+    );
+    let ribbon = commands
+        .spawn((
+            Name::new("Trail ribbon"),
+            Mesh2d(meshes.add(mesh)),
+            MeshMaterial2d(material.0.clone()),
+            Transform::default(),
+            Visibility::Hidden,
+            // the mesh moves about with the trail, so its bounds don't last
+            NoFrustumCulling,
+        ))
+        .id();
+    commands
+        .entity(trigger.target())
+        .insert(TrailRibbon(ribbon));
+}
+
+fn despawn_ribbon(
+    trigger: Trigger<OnRemove, Trail>,
+    mut commands: Commands,
+    ribbons: Query<&TrailRibbon>,
+) {
+    if let Ok(ribbon) = ribbons.get(trigger.target()) {
+        commands.entity(ribbon.0).try_despawn();
+    }
+}
+
+/// Ages the points of every trail, and records where the emitting ones are
+fn record_trails(time: Res<Time>, mut trails: Query<(&mut Trail, &GlobalTransform)>) {
+    let dt = time.delta_secs();
+    // nothing moves while paused, and recording would bunch the points up
+    if dt == 0.0 {
+        return;
+    }
+
+    for (mut trail, transform) in &mut trails {
+        let lifetime = trail.lifetime;
+        for point in trail.points.iter_mut() {
+            point.age += dt;
+        }
+        while trail
+            .points
+            .back()
+            .is_some_and(|point| point.age > lifetime)
+        {
+            trail.points.pop_back();
+        }
+
+        if trail.emitting {
+            trail.points.push_front(TrailPoint {
+                position: transform.translation(),
+                age: 0.0,
+            });
+            let length = trail.length;
+            trail.points.truncate(length);
+        }
+    }
+}
+
+/// Rebuilds each ribbon's mesh through its trail's points
+fn draw_ribbons(
+    trails: Query<(&Trail, &TrailRibbon), Changed<Trail>>,
+    mut ribbons: Query<(&Mesh2d, &mut Visibility)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+) {
+    for (trail, ribbon) in &trails {
+        let Ok((mesh, mut visibility)) = ribbons.get_mut(ribbon.0) else {
+            continue;
+        };
+        if trail.points.len() < 2 {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        }
+        visibility.set_if_neq(Visibility::Inherited);
+
+        let Some(mesh) = meshes.get_mut(&mesh.0) else {
+            continue;
+        };
+
+        let (start_color, end_color) = (
+            LinearRgba::from(trail.color.0),
+            LinearRgba::from(trail.color.1),
+        );
+        let last = trail.length.saturating_sub(1).max(1) as f32;
+
+        let mut positions = Vec::with_capacity(2 * trail.points.len());
+        let mut colors = Vec::with_capacity(2 * trail.points.len());
+        let mut direction = Vec2::Y;
+        for (i, point) in trail.points.iter().enumerate() {
+            // the ribbon runs along the path between its neighbours
+            let ahead = trail.points.get(i.wrapping_sub(1)).unwrap_or(point);
+            let behind = trail.points.get(i + 1).unwrap_or(point);
+            direction = (ahead.position - behind.position)
+                .truncate()
+                .try_normalize()
+                .unwrap_or(direction);
+
+            let along = i as f32 / last;
+            let width = trail.width.0.lerp(trail.width.1, along);
+            let side = direction.perp() * width / 2.0;
+            let centre = point.position.truncate();
+            let z = point.position.z - BEHIND;
+            positions.push((centre + side).extend(z));
+            positions.push((centre - side).extend(z));
+
+            let mut color = start_color.mix(&end_color, along);
+            color.alpha *= 1.0 - (point.age / trail.lifetime).clamp(0.0, 1.0);
+            colors.extend([color.to_f32_array(); 2]);
+        }
+
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, colors);
+    }
+}
@@ -0,0 +1,302 @@
+//! A scripted tutorial run that teaches the mechanics one step at a time.
+//!
+//! The nova timer and obstacle spawning are held until the player reaches the
+//! steps that need them, so they can take each mechanic at their own pace.
+
+use std::time::Duration;
+
+use bevy::{color::palettes::css::WHITE, prelude::*};
+use rand::Rng;
+
+use crate::{
+    consts::MAX_PLAYER_RADIUS,
+    locale::Locale,
+    materials::BarDataSource,
+    mode::GameMode,
+    obstacle::{Obstacle, PlayerCollision, SpawnObstacle},
+    player::{ItemPosition, Player, PlayerPower, PlayerShield},
+    rng::RunRng,
+    score::Score,
+    screens::Screen,
+    supernova::{Nova, NovaTimer},
+    theme::layout,
+    utils,
+};
+
+/// How long before the nova the tutorial skips to once it reaches that step
+const NOVA_LEAD_IN: Duration = Duration::from_secs(3);
+/// How long the "tutorial complete" message shows before returning to the title
+const COMPLETE_DELAY: f32 = 5.0;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<TutorialStep>();
+    app.register_type::<TutorialProgress>();
+    app.register_type::<TutorialPrompt>();
+
+    app.add_systems(
+        OnEnter(Screen::Gameplay),
+        setup_tutorial.run_if(resource_equals(GameMode::Tutorial)),
+    );
+    app.add_systems(OnExit(Screen::Gameplay), remove_tutorial);
+    app.add_systems(
+        Update,
+        (
+            check_step,
+            hurry_nova.run_if(in_state(Nova::Idle)),
+            update_prompt,
+        )
+            .chain()
+            .run_if(in_state(Screen::Gameplay).and(resource_exists::<TutorialProgress>)),
+    );
+    app.add_systems(
+        OnExit(Nova::After),
+        finish_nova_step.run_if(resource_exists::<TutorialProgress>),
+    );
+}
+
+/// The steps of the tutorial, in the order they are taught
+#[derive(Reflect, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TutorialStep {
+    #[default]
+    Thrust,
+    Skim,
+    Heat,
+    Multiplier,
+    Dodge,
+    Nova,
+    Complete,
+}
+
+impl TutorialStep {
+    fn next(&self) -> Self {
+        match self {
+            TutorialStep::Thrust => TutorialStep::Skim,
+            TutorialStep::Skim => TutorialStep::Heat,
+            TutorialStep::Heat => TutorialStep::Multiplier,
+            TutorialStep::Multiplier => TutorialStep::Dodge,
+            TutorialStep::Dodge => TutorialStep::Nova,
+            TutorialStep::Nova | TutorialStep::Complete => TutorialStep::Complete,
+        }
+    }
+
+    /// The locale key for the prompt shown during the step
+    fn prompt_key(&self, progress: &TutorialProgress) -> &'static str {
+        match self {
+            TutorialStep::Thrust => "tutorial.thrust",
+            TutorialStep::Skim => "tutorial.skim",
+            TutorialStep::Heat if progress.flag => "tutorial.heat.hot",
+            TutorialStep::Heat => "tutorial.heat",
+            TutorialStep::Multiplier => "tutorial.multiplier",
+            TutorialStep::Dodge if progress.failed => "tutorial.dodge.failed",
+            TutorialStep::Dodge => "tutorial.dodge",
+            TutorialStep::Nova if progress.flag => "tutorial.nova.retry",
+            TutorialStep::Nova => "tutorial.nova",
+            TutorialStep::Complete => "tutorial.complete",
+        }
+    }
+}
+
+#[derive(Resource, Reflect, Debug, Default)]
+#[reflect(Resource)]
+pub struct TutorialProgress {
+    pub step: TutorialStep,
+    /// Step specific progress, i.e. whether the shield has heated up yet, the
+    /// flares to dodge have been spawned or the nova is being repeated
+    flag: bool,
+    /// Whether the player hit something they should have avoided this step
+    failed: bool,
+    /// The multiplier when the current step started
+    start_multiplier: u32,
+    /// Seconds spent in the current step
+    elapsed: f32,
+}
+
+impl TutorialProgress {
+    fn advance(&mut self, score: &Score) {
+        *self = Self {
+            step: self.step.next(),
+            start_multiplier: score.multiplier,
+            ..default()
+        };
+    }
+}
+
+/// Run condition that holds obstacle spawning for the whole tutorial, flares
+/// are spawned by the tutorial itself when it reaches the dodging step
+pub fn tutorial_allows_obstacles(progress: Option<Res<TutorialProgress>>) -> bool {
+    progress.is_none()
+}
+
+/// Run condition that holds the [`NovaTimer`] until the tutorial reaches the
+/// nova step
+pub fn tutorial_allows_nova(progress: Option<Res<TutorialProgress>>) -> bool {
+    progress.is_none_or(|progress| progress.step == TutorialStep::Nova)
+}
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct TutorialPrompt;
+
+fn setup_tutorial(mut commands: Commands) {
+    commands.init_resource::<TutorialProgress>();
+
+    commands.spawn((
+        Name::new("Tutorial Prompt"),
+        StateScoped(Screen::Gameplay),
+        // sits above the minimap once it no longer fits beside it
+        layout::responsive(prompt_node(40.0), prompt_node(200.0)),
+        Pickable::IGNORE,
+        children![(
+            Text::new(""),
+            TextLayout {
+                justify: JustifyText::Center,
+                ..default()
+            },
+            layout::font_size(20.0, 16.0),
+            TextColor(WHITE.into()),
+            TutorialPrompt,
+        )],
+    ));
+}
+
+fn prompt_node(bottom: f32) -> Node {
+    Node {
+        position_type: PositionType::Absolute,
+        bottom: Val::Px(bottom),
+        left: Val::ZERO,
+        width: Val::Percent(100.0),
+        padding: UiRect::horizontal(Val::Px(20.0)),
+        justify_content: JustifyContent::Center,
+        ..default()
+    }
+}
+
+fn remove_tutorial(mut commands: Commands) {
+    commands.remove_resource::<TutorialProgress>();
+}
+
+fn check_step(
+    mut commands: Commands,
+    time: Res<Time>,
+    score: Res<Score>,
+    mut progress: ResMut<TutorialProgress>,
+    mut run_rng: ResMut<RunRng>,
+    mut collisions: EventReader<PlayerCollision>,
+    mut next_screen: ResMut<NextState<Screen>>,
+    obstacles: Query<Entity, With<Obstacle>>,
+    player: Single<(&ItemPosition, &PlayerPower, &PlayerShield), With<Player>>,
+) {
+    let (position, power, shield) = player.into_inner();
+    progress.elapsed += time.delta_secs();
+
+    for collision in collisions.read() {
+        match (progress.step, collision) {
+            (TutorialStep::Dodge, PlayerCollision::Obstacle) => {
+                // clear the remaining flares and send a new wave. The one
+                // that was hit is already gone.
+                for entity in &obstacles {
+                    commands.entity(entity).try_despawn();
+                }
+                progress.flag = false;
+                progress.failed = true;
+            }
+            (TutorialStep::Nova, PlayerCollision::BlackHole) => progress.failed = true,
+            _ => {}
+        }
+    }
+
+    let step_complete = match progress.step {
+        TutorialStep::Thrust => position.radius > 450.0,
+        TutorialStep::Skim => power.0 > 30.0,
+        TutorialStep::Heat => {
+            if shield.current_frac() < 0.6 {
+                progress.flag = true;
+            }
+
+            progress.flag && shield.current_frac() > 0.9
+        }
+        TutorialStep::Multiplier => score.multiplier > progress.start_multiplier,
+        TutorialStep::Dodge => {
+            if !progress.flag {
+                spawn_flares(&mut commands, &time, &score, &mut run_rng, position);
+                progress.flag = true;
+                false
+            } else {
+                // the flares expired without being hit
+                obstacles.is_empty()
+            }
+        }
+        // completed when the warp finishes, see `finish_nova_step`
+        TutorialStep::Nova => false,
+        TutorialStep::Complete => {
+            if progress.elapsed > COMPLETE_DELAY {
+                next_screen.set(Screen::Title);
+            }
+
+            false
+        }
+    };
+
+    if step_complete {
+        progress.advance(&score);
+    }
+}
+
+/// Spawns a handful of flares on the far side of the orbit, a little above
+/// the player so they have to move to dodge them
+fn spawn_flares(
+    commands: &mut Commands,
+    time: &Time,
+    score: &Score,
+    run_rng: &mut RunRng,
+    position: &ItemPosition,
+) {
+    let rng = &mut run_rng.obstacles;
+    let radius = (position.radius + 100.0).clamp(60.0, MAX_PLAYER_RADIUS * 0.5);
+
+    let extra_speed = utils::get_player_speed_multipliers(score.multiplier, position, Nova::Idle);
+    let speed = position.speed * (extra_speed.0 + extra_speed.1);
+
+    for _ in 0..3 {
+        commands.queue(SpawnObstacle {
+            theta: position.theta + std::f32::consts::PI + rng.gen_range(-0.05..=0.05),
+            radius: radius + rng.gen_range(-40.0..40.0),
+            speed: rng.gen_range(-30.0..-15.0),
+            // destroy after one revolution at the player's current speed
+            destroy_at: std::f32::consts::TAU / speed + time.elapsed_secs(),
+        });
+    }
+}
+
+/// Skips most of the idle phase once the tutorial reaches the nova step
+fn hurry_nova(progress: Res<TutorialProgress>, mut timer: ResMut<NovaTimer>) {
+    if progress.step != TutorialStep::Nova || timer.0.remaining() <= NOVA_LEAD_IN {
+        return;
+    }
+
+    let elapsed = timer.0.duration().saturating_sub(NOVA_LEAD_IN);
+    timer.0.set_elapsed(elapsed);
+}
+
+/// Completes the nova step once the warp is over, or repeats it if the player
+/// hit a black hole on the way
+fn finish_nova_step(score: Res<Score>, mut progress: ResMut<TutorialProgress>) {
+    if progress.step != TutorialStep::Nova {
+        return;
+    }
+
+    if progress.failed {
+        progress.failed = false;
+        progress.flag = true;
+    } else {
+        progress.advance(&score);
+    }
+}
+
+fn update_prompt(
+    progress: Res<TutorialProgress>,
+    locale: Res<Locale>,
+    mut text: Single<&mut Text, With<TutorialPrompt>>,
+) {
+    text.0 = locale.get(progress.step.prompt_key(&progress)).into();
+}
@@ -0,0 +1,133 @@
+//! Permanent ship upgrades, bought between runs with credits earned from
+//! each run's score.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{mode::GameMode, player::ShipStats, save::SaveData, score::Score, screens::Screen};
+
+/// The number of points of score needed to earn a single credit
+const SCORE_PER_CREDIT: f32 = 50.0;
+
+/// The highest level each upgrade can be bought to
+pub const MAX_UPGRADE_LEVEL: u8 = 5;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<Upgrade>();
+    app.register_type::<Upgrades>();
+    app.register_type::<CreditsEarned>();
+
+    app.init_resource::<CreditsEarned>();
+
+    app.add_systems(OnEnter(Screen::Gameplay), reset_credits_earned);
+    app.add_systems(OnExit(Screen::Gameplay), award_credits);
+}
+
+#[derive(Reflect, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Upgrade {
+    /// Raises the maximum shield
+    MaxShield,
+    /// Reduces the shield lost when hitting an obstacle
+    Plating,
+    /// Moves towards and away from the sun faster
+    Thrusters,
+    /// Generates more power while close to the sun
+    PowerCore,
+}
+
+impl Upgrade {
+    /// The locale key for the upgrade's name
+    pub fn name_key(&self) -> &'static str {
+        match self {
+            Upgrade::MaxShield => "upgrade.max_shield",
+            Upgrade::Plating => "upgrade.plating",
+            Upgrade::Thrusters => "upgrade.thrusters",
+            Upgrade::PowerCore => "upgrade.power_core",
+        }
+    }
+
+    /// The cost of buying the next level, if the upgrade isn't maxed out
+    pub fn cost(&self, current_level: u8) -> Option<u32> {
+        (current_level < MAX_UPGRADE_LEVEL).then(|| 25 * (current_level as u32 + 1))
+    }
+}
+
+/// The level of each upgrade the player has bought
+#[derive(Reflect, Serialize, Deserialize, Debug, Default, Clone)]
+#[serde(default)]
+pub struct Upgrades {
+    pub max_shield: u8,
+    pub plating: u8,
+    pub thrusters: u8,
+    pub power_core: u8,
+}
+
+impl Upgrades {
+    pub fn level(&self, upgrade: Upgrade) -> u8 {
+        match upgrade {
+            Upgrade::MaxShield => self.max_shield,
+            Upgrade::Plating => self.plating,
+            Upgrade::Thrusters => self.thrusters,
+            Upgrade::PowerCore => self.power_core,
+        }
+    }
+
+    fn level_mut(&mut self, upgrade: Upgrade) -> &mut u8 {
+        match upgrade {
+            Upgrade::MaxShield => &mut self.max_shield,
+            Upgrade::Plating => &mut self.plating,
+            Upgrade::Thrusters => &mut self.thrusters,
+            Upgrade::PowerCore => &mut self.power_core,
+        }
+    }
+
+    /// Applies the bought upgrades on top of the ship's base stats
+    pub fn apply(&self, stats: &mut ShipStats) {
+        stats.max_shield += 15.0 * self.max_shield as f32;
+        stats.obstacle_shield_cost -= 4.0 * self.plating as f32;
+        stats.radius_change_speed *= 1.0 + 0.15 * self.thrusters as f32;
+        stats.power_generation *= 1.0 + 0.08 * self.power_core as f32;
+    }
+}
+
+impl SaveData {
+    /// Buys the next level of an upgrade, returning false if the player can't
+    /// afford it or it is already at the maximum level
+    pub fn buy_upgrade(&mut self, upgrade: Upgrade) -> bool {
+        let level = self.upgrades.level(upgrade);
+        let Some(cost) = upgrade.cost(level).filter(|cost| *cost <= self.credits) else {
+            return false;
+        };
+
+        self.credits -= cost;
+        *self.upgrades.level_mut(upgrade) += 1;
+        true
+    }
+}
+
+/// The number of credits earned in the last run
+#[derive(Resource, Reflect, Debug, Default)]
+#[reflect(Resource)]
+pub struct CreditsEarned(pub u32);
+
+fn reset_credits_earned(mut earned: ResMut<CreditsEarned>) {
+    earned.0 = 0;
+}
+
+fn award_credits(
+    mode: Res<GameMode>,
+    score: Option<Res<Score>>,
+    mut earned: ResMut<CreditsEarned>,
+    mut save: ResMut<SaveData>,
+) {
+    let Some(score) = score else {
+        return;
+    };
+
+    if !mode.earns_credits() {
+        return;
+    }
+
+    earned.0 = (score.score / SCORE_PER_CREDIT) as u32;
+    save.credits += earned.0;
+}
@@ -1,114 +1,137 @@
-use bevy::prelude::*;
-
-use crate::{player::ItemPosition, supernova::Nova};
-
-pub(super) fn plugin(app: &mut App) {
-    app.register_type::<Rotate>();
-    app.add_systems(
-        Update,
-        (
-            rotate_shapes,
-            destroy_at_watcher,
-            text_scaling_system,
-            move_items_in_direction,
-        ),
-    );
-}
-
-pub fn format_number(number: f32) -> String {
-    format!("{number:.0}")
-}
-
-/// Marks the shape to rotate around the z-axis with the given speed
-/// in units per second
-#[derive(Component, Debug, Reflect)]
-#[reflect(Component)]
-pub struct Rotate(pub f32);
-
-impl Default for Rotate {
-    fn default() -> Self {
-        Self(1.0)
-    }
-}
-
-/// Rotates shapes a little bit each frame based on the rotation speed
-fn rotate_shapes(time: Res<Time>, mut shapes: Query<(&mut Transform, &Rotate)>) {
-    for (mut tx, rot) in &mut shapes {
-        tx.rotation *= Quat::from_axis_angle(Vec3::Z, rot.0 * time.delta_secs());
-    }
-}
-
-#[derive(Component, Debug, Reflect)]
-#[reflect(Component)]
-pub struct DestroyAt {
-    pub time: f32,
-}
-
-/// Destroys [DestroyAt] components after their scheduled time
-fn destroy_at_watcher(
-    mut commands: Commands,
-    time: Res<Time>,
-    destroyees: Query<(Entity, &DestroyAt)>,
-) {
-    let elapsed = time.elapsed_secs();
-    for (ent, destroyee) in &destroyees {
-        if elapsed > destroyee.time {
-            commands.entity(ent).despawn();
-        }
-    }
-}
-
-#[derive(Component, Reflect, Debug)]
-#[reflect(Component)]
-pub struct ScaleTextOverTime {
-    pub rate: f32,
-    pub max: f32,
-}
-
-impl ScaleTextOverTime {
-    fn update(&self, dt: f32, current: f32) -> f32 {
-        let amount = self.rate * dt;
-        (current + amount).min(self.max)
-    }
-}
-
-fn text_scaling_system(time: Res<Time>, mut texts: Query<(&mut TextFont, &ScaleTextOverTime)>) {
-    for (mut font, scale) in &mut texts {
-        font.font_size = scale.update(time.delta_secs(), font.font_size);
-    }
-}
-
-#[derive(Component, Reflect, Debug)]
-#[reflect(Component)]
-pub struct MoveInDirection(pub Vec2);
-
-pub fn move_items_in_direction(
-    time: Res<Time>,
-    mut items: Query<(&mut Transform, &MoveInDirection)>,
-) {
-    for (mut tx, mover) in &mut items {
-        tx.translation += (time.delta_secs() * mover.0).extend(0.0);
-    }
-}
-
-/// Returns the player speed multipliers for (1) the state of the nova and (2)
-/// the level multiplier. Add the two together to get the total speed
-pub fn get_player_speed_multipliers(
-    multiplier: u32,
-    position: &ItemPosition,
-    nova_state: Nova,
-) -> (f32, f32) {
-    let speed_multiplier = match nova_state {
-        Nova::Idle => {
-            if position.radius < 1.0 {
-                1.5
-            } else {
-                1.0
-            }
-        }
-        Nova::BuildingUp => 1.0,
-        _ => 0.8,
-    };
-
-    (speed_multiplier, multiplier as f32 / 20.0)
-}
+use bevy::prelude::*;
+
+use crate::{locale::Language, player::ItemPosition, supernova::Nova};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        (
+            destroy_at_watcher,
+            text_scaling_system,
+            move_items_in_direction,
+        ),
+    );
+}
+
+/// Formats a whole number, grouping the digits the way the language does
+pub fn format_number(number: f32, language: Language) -> String {
+    let digits = format!("{:.0}", number.abs());
+    let separator = language.thousands_separator();
+
+    let mut grouped = String::new();
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(separator);
+        }
+        grouped.push(digit);
+    }
+
+    if number <= -0.5 {
+        format!("-{grouped}")
+    } else {
+        grouped
+    }
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct DestroyAt {
+    pub time: f32,
+}
+
+/// Destroys [DestroyAt] components after their scheduled time
+fn destroy_at_watcher(
+    mut commands: Commands,
+    time: Res<Time>,
+    destroyees: Query<(Entity, &DestroyAt)>,
+) {
+    let elapsed = time.elapsed_secs();
+    for (ent, destroyee) in &destroyees {
+        if elapsed > destroyee.time {
+            commands.entity(ent).despawn();
+        }
+    }
+}
+
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+pub struct ScaleTextOverTime {
+    pub rate: f32,
+    pub max: f32,
+}
+
+impl ScaleTextOverTime {
+    fn update(&self, dt: f32, current: f32) -> f32 {
+        let amount = self.rate * dt;
+        (current + amount).min(self.max)
+    }
+}
+
+fn text_scaling_system(time: Res<Time>, mut texts: Query<(&mut TextFont, &ScaleTextOverTime)>) {
+    for (mut font, scale) in &mut texts {
+        font.font_size = scale.update(time.delta_secs(), font.font_size);
+    }
+}
+
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+pub struct MoveInDirection(pub Vec2);
+
+pub fn move_items_in_direction(
+    time: Res<Time>,
+    mut items: Query<(&mut Transform, &MoveInDirection)>,
+) {
+    for (mut tx, mover) in &mut items {
+        tx.translation += (time.delta_secs() * mover.0).extend(0.0);
+    }
+}
+
+/// Returns the player speed multipliers for (1) the state of the nova and (2)
+/// the level multiplier. Add the two together to get the total speed
+pub fn get_player_speed_multipliers(
+    multiplier: u32,
+    position: &ItemPosition,
+    nova_state: Nova,
+) -> (f32, f32) {
+    let speed_multiplier = match nova_state {
+        Nova::Idle => {
+            if position.radius < 1.0 {
+                1.5
+            } else {
+                1.0
+            }
+        }
+        Nova::BuildingUp => 1.0,
+        _ => 0.8,
+    };
+
+    (speed_multiplier, multiplier as f32 / 20.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers_are_grouped_for_each_language() {
+        for (language, separator) in [
+            (Language::English, ","),
+            (Language::Spanish, "."),
+            (Language::French, " "),
+            (Language::German, "."),
+        ] {
+            let grouped = |digits: &str| digits.replace('_', separator);
+            let format = |number| format_number(number, language);
+
+            assert_eq!(format(0.0), "0");
+            assert_eq!(format(999.0), "999");
+            assert_eq!(format(1000.0), grouped("1_000"));
+            assert_eq!(format(1_234_567.0), grouped("1_234_567"));
+            assert_eq!(format(-1_234_567.0), grouped("-1_234_567"));
+            assert_eq!(format(-999.0), "-999");
+            // rounds to zero, so there's nothing to be negative
+            assert_eq!(format(-0.4), "0");
+            assert_eq!(format(-0.6), "-1");
+        }
+    }
+}